@@ -0,0 +1,304 @@
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+use regex::{Regex, RegexBuilder};
+use rusqlite::{functions::FunctionFlags, Connection};
+
+use crate::search::{
+    mana_cost::{ManaColor, ManaCost, ManaSymbolKind},
+    numeric_operator::numeric_operator,
+};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+// Registers the Rust side functions that the generated SQL relies on. Every
+// connection that runs a search needs these.
+pub fn add_functions(conn: &Connection) -> rusqlite::Result<()> {
+    add_regexp_function(conn)?;
+    add_mana_cost_functions(conn)?;
+    add_rules_text_functions(conn)
+}
+
+// SQLite doesn't ship with an implementation of `REGEXP`, so `x REGEXP y` calls
+// this as `regexp(y, x)`. Like Scryfall, matching is case-insensitive. The
+// compiled regex is cached for the duration of the statement.
+fn add_regexp_function(conn: &Connection) -> rusqlite::Result<()> {
+    conn.create_scalar_function(
+        "regexp",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let regex: Arc<Regex> = ctx.get_or_create_aux(0, |pattern| -> Result<_, BoxError> {
+                Ok(RegexBuilder::new(pattern.as_str()?)
+                    .case_insensitive(true)
+                    .build()?)
+            })?;
+            let text = ctx
+                .get_raw(1)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?;
+            Ok(text.is_some_and(|text| regex.is_match(text)))
+        },
+    )
+}
+
+// Mana costs are stored as text like `{2}{W}{W}`, so comparing them as
+// multisets of symbols happens in Rust using the same model as the parser.
+//
+// `mana_cost_compare(mana_cost, query, operator)` compares a card's cost
+// against a query cost with one of the numeric operators, and
+// `mana_cost_has(mana_cost, kind)` checks for hybrid or phyrexian symbols. A
+// card without a mana cost, such as a land, never matches.
+// `mana_cost_devotion(mana_cost, colors)` counts the symbols that contribute
+// to devotion to any of the colors, which are given as letters like `UB`.
+fn add_mana_cost_functions(conn: &Connection) -> rusqlite::Result<()> {
+    conn.create_scalar_function(
+        "mana_cost_compare",
+        3,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let query: Arc<ManaCost> = ctx
+                .get_or_create_aux(1, |query| -> Result<_, BoxError> {
+                    Ok(ManaCost::from_card(query.as_str()?))
+                })?;
+            let operator = ctx.get_or_create_aux(2, |operator| -> Result<_, BoxError> {
+                let operator = operator.as_str()?;
+                match numeric_operator(operator) {
+                    Ok(("", operator)) => Ok(operator),
+                    _ => Err(format!("Invalid mana cost operator: {operator}").into()),
+                }
+            })?;
+            let mana_cost = ctx
+                .get_raw(0)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?
+                .filter(|mana_cost| !mana_cost.is_empty());
+            Ok(mana_cost
+                .is_some_and(|mana_cost| ManaCost::from_card(mana_cost).compare(&operator, &query)))
+        },
+    )?;
+    conn.create_scalar_function(
+        "mana_cost_has",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let kind = ctx.get_or_create_aux(1, |kind| -> Result<_, BoxError> {
+                let kind = kind.as_str()?;
+                ManaSymbolKind::from_keyword(kind)
+                    .ok_or_else(|| format!("Invalid mana symbol kind: {kind}").into())
+            })?;
+            let mana_cost = ctx
+                .get_raw(0)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?
+                .filter(|mana_cost| !mana_cost.is_empty());
+            Ok(mana_cost.is_some_and(|mana_cost| kind.matches(&ManaCost::from_card(mana_cost))))
+        },
+    )?;
+    conn.create_scalar_function(
+        "mana_cost_devotion",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let colors = ctx.get_or_create_aux(1, |colors| -> Result<_, BoxError> {
+                let colors = colors.as_str()?;
+                colors
+                    .chars()
+                    .map(|color| ManaColor::from_letter(&color.to_string()))
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| format!("Invalid devotion colors: {colors}").into())
+            })?;
+            let mana_cost = ctx
+                .get_raw(0)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?;
+            Ok(mana_cost.map_or(0, |mana_cost| {
+                ManaCost::from_card(mana_cost).devotion(&colors)
+            }))
+        },
+    )
+}
+
+lazy_static! {
+    static ref REMINDER_TEXT: Regex = Regex::new(r"\([^)]*\)").unwrap();
+}
+
+// Reminder text is anything in parentheses. It doesn't change what a card
+// does, so it's ignored when deciding whether a card has any rules text.
+fn strip_reminder_text(text: &str) -> String {
+    REMINDER_TEXT.replace_all(text, "").trim().to_string()
+}
+
+// A card has only keywords if every ability on it, once the reminder text is
+// removed, is one of the card's keywords along with its cost or quality, e.g.
+// `Flying`, `Ward {2}` or `Protection from red`. `keywords` is the card's
+// keywords joined with `|`.
+fn only_keywords(text: &str, keywords: &str) -> bool {
+    let keywords = keywords
+        .split('|')
+        .filter(|keyword| !keyword.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>();
+    let abilities = strip_reminder_text(text)
+        .split(['\n', ','])
+        .map(|ability| ability.trim().to_lowercase())
+        .filter(|ability| !ability.is_empty())
+        .collect::<Vec<_>>();
+    !abilities.is_empty()
+        && abilities.iter().all(|ability| {
+            !ability.contains(['.', ':', '—'])
+                && keywords.iter().any(|keyword| {
+                    ability == keyword || ability.starts_with(&format!("{keyword} "))
+                })
+        })
+}
+
+// `strip_reminder_text(text)` removes the reminder text from rules text, and
+// `only_keywords(text, keywords)` checks whether rules text is made up of
+// nothing but the card's keywords. Both treat a NULL text as empty.
+fn add_rules_text_functions(conn: &Connection) -> rusqlite::Result<()> {
+    conn.create_scalar_function(
+        "strip_reminder_text",
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let text = ctx
+                .get_raw(0)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?;
+            Ok(strip_reminder_text(text.unwrap_or_default()))
+        },
+    )?;
+    conn.create_scalar_function(
+        "only_keywords",
+        2,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| {
+            let text = ctx
+                .get_raw(0)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?;
+            let keywords = ctx
+                .get_raw(1)
+                .as_str_or_null()
+                .map_err(|e| rusqlite::Error::UserFunctionError(e.into()))?;
+            Ok(only_keywords(
+                text.unwrap_or_default(),
+                keywords.unwrap_or_default(),
+            ))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query_bool(sql: &str, params: impl rusqlite::Params) -> bool {
+        let conn = Connection::open_in_memory().unwrap();
+        add_functions(&conn).unwrap();
+        conn.query_row(sql, params, |row| row.get(0)).unwrap()
+    }
+
+    fn regexp_matches(pattern: &str, text: Option<&str>) -> bool {
+        query_bool("SELECT ?2 REGEXP ?1", rusqlite::params![pattern, text])
+    }
+
+    #[test]
+    fn regexp_is_case_insensitive() {
+        assert!(regexp_matches("^fire", Some("Fire // Ice")));
+        assert!(regexp_matches(r"\bice$", Some("Fire // Ice")));
+        assert!(!regexp_matches("^ice", Some("Fire // Ice")));
+    }
+
+    #[test]
+    fn regexp_does_not_match_null() {
+        assert!(!regexp_matches(".*", None));
+    }
+
+    fn mana_cost_compare(mana_cost: Option<&str>, query: &str, operator: &str) -> bool {
+        query_bool(
+            "SELECT mana_cost_compare(?1, ?2, ?3)",
+            rusqlite::params![mana_cost, query, operator],
+        )
+    }
+
+    #[test]
+    fn mana_cost_compare_uses_operator() {
+        assert!(mana_cost_compare(Some("{3}{W}{U}"), "{W}{U}", ":"));
+        assert!(mana_cost_compare(Some("{3}{W}{U}"), "{2}{W}{U}", ">"));
+        assert!(!mana_cost_compare(Some("{3}{W}{U}"), "{2}{W}{U}", "<"));
+        assert!(!mana_cost_compare(None, "{W}", "<="));
+        assert!(!mana_cost_compare(Some(""), "{W}", "<="));
+    }
+
+    #[test]
+    fn mana_cost_has_hybrid_and_phyrexian() {
+        let has = |mana_cost: &str, kind: &str| {
+            query_bool(
+                "SELECT mana_cost_has(?1, ?2)",
+                rusqlite::params![mana_cost, kind],
+            )
+        };
+        assert!(has("{2/G}{2/G}{2/G}", "hybrid"));
+        assert!(!has("{2/G}{2/G}{2/G}", "phyrexian"));
+        assert!(has("{3}{R/P}", "phyrexian"));
+    }
+
+    #[test]
+    fn mana_cost_devotion_counts_symbols() {
+        let devotion = |mana_cost: Option<&str>, colors: &str| -> i64 {
+            let conn = Connection::open_in_memory().unwrap();
+            add_functions(&conn).unwrap();
+            conn.query_row(
+                "SELECT mana_cost_devotion(?1, ?2)",
+                rusqlite::params![mana_cost, colors],
+                |row| row.get(0),
+            )
+            .unwrap()
+        };
+        assert_eq!(devotion(Some("{1}{U}{U/B}{B}"), "UB"), 3);
+        assert_eq!(devotion(Some("{1}{U}{U/B}{B}"), "U"), 2);
+        assert_eq!(devotion(None, "U"), 0);
+    }
+
+    #[test]
+    fn reminder_text_is_stripped() {
+        assert_eq!(
+            strip_reminder_text(
+                "(Dryad Arbor isn't a spell, it's affected by summoning sickness.)"
+            ),
+            ""
+        );
+        assert_eq!(
+            strip_reminder_text(
+                "Flying (This creature can't be blocked except by creatures with flying or reach.)"
+            ),
+            "Flying"
+        );
+    }
+
+    #[test]
+    fn only_keywords_allows_costs_and_qualities() {
+        assert!(only_keywords("Flying\nVigilance", "Flying|Vigilance"));
+        assert!(only_keywords("Flying, vigilance", "Flying|Vigilance"));
+        assert!(only_keywords("Ward {2}", "Ward"));
+        assert!(only_keywords(
+            "Protection from red (This creature can't be blocked by red creatures.)",
+            "Protection"
+        ));
+    }
+
+    #[test]
+    fn only_keywords_rejects_other_abilities() {
+        assert!(!only_keywords("", ""));
+        assert!(!only_keywords(
+            "Flying\nWhen this creature dies, draw a card.",
+            "Flying"
+        ));
+        assert!(!only_keywords(
+            "Landfall — Whenever a land you control enters, you gain 1 life.",
+            "Landfall"
+        ));
+    }
+}
@@ -1,5 +1,6 @@
-SELECT MIN(cards.id) as id
+SELECT cards.id, MAX({preferred}) as preferred
 FROM cards{joins}{clauses}
-GROUP BY cards.name
+GROUP BY {group}
+ORDER BY {order}
 LIMIT :limit
 OFFSET :cursor;
\ No newline at end of file
@@ -1,16 +1,20 @@
 use std::path::PathBuf;
+mod functions;
 pub mod to_sql;
 
 use anyhow::Context;
 use bytes::Bytes;
 use rusqlite::{
     named_params,
-    types::{FromSql, ToSqlOutput, ValueRef},
+    types::{FromSql, ToSqlOutput, Value, ValueRef},
     ToSql,
 };
 use tokio::spawn;
 
-use self::to_sql::{ToSql as _, SQL};
+use self::{
+    functions::add_functions,
+    to_sql::{ToSql as _, PREFERRED_PRINT},
+};
 use crate::{card::card_data::CardData, search::Search, CARDS_PER_ROW};
 
 pub struct Database;
@@ -20,7 +24,9 @@ impl Database {
         PathBuf::from("target").join("cards.sqlite")
     }
     pub async fn connection() -> tokio_rusqlite::Result<tokio_rusqlite::Connection> {
-        tokio_rusqlite::Connection::open(Database::path()).await
+        let conn = tokio_rusqlite::Connection::open(Database::path()).await?;
+        conn.call(|conn| Ok(add_functions(conn)?)).await?;
+        Ok(conn)
     }
 
     pub async fn get_card_face(
@@ -69,11 +75,8 @@ impl Database {
         }
     }
 
-    fn fetch_card_ids_sql(search: Search) -> String {
-        let s = search
-            .parsed_search
-            .map(|s| s.to_sql())
-            .unwrap_or(SQL::default());
+    fn fetch_card_ids_sql(search: Search) -> (String, Vec<Value>) {
+        let s = search.to_sql();
         let mut joins = s.joins();
         if !joins.is_empty() {
             joins = format!("\n{joins}");
@@ -85,10 +88,13 @@ impl Database {
         let sql = format!(
             include_str!("get_ids_with_clauses.sql"),
             joins = joins,
-            clauses = clauses
+            clauses = clauses,
+            preferred = PREFERRED_PRINT,
+            group = search.group_by(),
+            order = search.order_by()
         );
         // println!("{}\n", sql);
-        sql
+        (sql, s.params().to_vec())
     }
 
     pub async fn fetch_card_ids(
@@ -97,12 +103,25 @@ impl Database {
     ) -> Result<Vec<String>, anyhow::Error> {
         let conn = Database::connection().await?;
         conn.call(move |conn| {
-            let mut stmt = conn.prepare(&Self::fetch_card_ids_sql(search))?;
+            let (sql, params) = Self::fetch_card_ids_sql(search);
+            let mut stmt = conn.prepare(&sql)?;
+            // The search params are positional, so they're bound first and
+            // the paging params are bound by name afterwards.
+            for (idx, param) in params.iter().enumerate() {
+                stmt.raw_bind_parameter(idx + 1, param)?;
+            }
+            for (name, value) in [(":cursor", cursor), (":limit", CARDS_PER_ROW)] {
+                let idx = stmt
+                    .parameter_index(name)?
+                    .ok_or_else(|| rusqlite::Error::InvalidParameterName(name.to_string()))?;
+                stmt.raw_bind_parameter(idx, value)?;
+            }
             let card_ids = stmt
-                .query_map(&[(":cursor", &cursor), (":limit", &CARDS_PER_ROW)], |row| {
+                .raw_query()
+                .mapped(|row| {
                     let id: String = row.get(0)?;
                     Ok(id)
-                })?
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             Ok(card_ids)
         })
@@ -221,10 +240,11 @@ mod test {
 
     #[test]
     fn empty_typeline_does_not_show_up() {
-        let actual = super::Database::fetch_card_ids_sql(Search::from("t:"));
+        let (actual, params) = super::Database::fetch_card_ids_sql(Search::from("t:"));
         assert!(
             !actual.contains("cards.type_line LIKE"),
             "An empty typeline shouldn't influence the query."
         );
+        assert!(params.is_empty());
     }
 }
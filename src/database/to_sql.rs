@@ -2,20 +2,53 @@ use std::{collections::HashSet, iter::once, sync::Mutex};
 
 use itertools::Itertools;
 use lazy_static::lazy_static;
+use regex::Regex;
+use rusqlite::types::Value;
 
 use crate::search::{
+    artist_query::{ArtistQuery, ArtistsQuery},
+    collector_number_query::CollectorNumberQuery,
     color::ColorOperand,
+    color_count_query::{ColorCount, ColorCountQuery},
     color_identity_query::{ColorIdentityOperator, ColorIdentityQuery},
+    date_query::{DateOperand, DateQuery, YearQuery},
+    devotion_query::DevotionQuery,
+    flavor_query::FlavorQuery,
+    frame_query::{BorderQuery, FrameQuery, StampQuery},
+    game_query::GameQuery,
+    id_query::{GameId, IdQuery},
+    in_query::InQuery,
+    include_query::IncludeQuery,
+    indicator_query::IndicatorQuery,
+    is_query::{IsPredicate, IsQuery},
     keyword::KeywordQuery,
+    language_query::LanguageQuery,
+    legality_query::{Legality, LegalityQuery},
+    mana_cost::ManaSymbolKind,
+    mana_cost_query::ManaCostQuery,
+    mana_value_query::{ManaValueOperand, ManaValueQuery},
+    numeric_operator::NumericOperator,
     oracle_query::OracleQuery,
+    order_query::{Direction, DirectionQuery, OrderQuery, SortOrder},
+    price_query::{CheapestQuery, Currency, PriceQuery},
+    prints_query::{PrintCount, PrintsQuery},
+    produces_query::ProducesQuery,
+    rarity_query::{Rarity, RarityQuery},
+    set_query::{SetQuery, SetTypeQuery},
+    shortcut::ShortcutQuery,
     type_line_query::TypeLineQuery,
-    ColorOperator, ColorQuery, Name, ParsedSearch, PowerOperand, PowerOperator, PowerQuery,
-    SearchKeyword,
+    unique_query::{Unique, UniqueQuery},
+    watermark_query::WatermarkQuery,
+    ColorOperator, ColorQuery, Name, ParsedSearch, Search, SearchKeyword, Stat, StatOperand,
+    StatQuery, TextOperand,
 };
 
 lazy_static! {
     // Define a static Mutex-protected counter
     static ref COUNTER: Mutex<usize> = Mutex::new(0);
+    static ref CARDNAME: Regex = Regex::new("(?i)cardname").unwrap();
+    static ref EXTRA_TYPES: Regex =
+        Regex::new(r"(?i)\b(token|emblem|vanguard|scheme|plane|phenomenon)\b").unwrap();
 }
 
 // Generate a unique table name
@@ -26,10 +59,15 @@ fn generate_table_name() -> String {
     table_name
 }
 
+// Any user provided text must be passed in through `params` rather than
+// spliced into the clauses. Each `?` in `where_clauses` is bound, in order, to
+// the matching entry in `params`. Join clauses are expected to be static and
+// shouldn't contain any placeholders.
 #[derive(Debug)]
 pub struct SQL {
     where_clauses: String,
     join_clauses: Vec<String>,
+    params: Vec<Value>,
 }
 
 impl Default for SQL {
@@ -37,6 +75,7 @@ impl Default for SQL {
         SQL {
             where_clauses: "".to_string(),
             join_clauses: vec![],
+            params: vec![],
         }
     }
 }
@@ -46,13 +85,56 @@ impl SQL {
         SQL {
             where_clauses,
             join_clauses,
+            params: vec![],
         }
     }
 
+    pub fn with_params(mut self, params: Vec<Value>) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub fn params(&self) -> &[Value] {
+        &self.params
+    }
+
     pub fn joins(&self) -> String {
         self.join_clauses.join("\n")
     }
 
+    // Joins the clauses with `AND` or `OR`, skipping any that are empty. Each
+    // clause is wrapped in parentheses so that an `OR` inside one of them
+    // can't take the others along with it.
+    fn combine(sqls: Vec<SQL>, operator: &str) -> SQL {
+        let sqls = sqls
+            .into_iter()
+            .filter(|sql| !sql.where_clauses.is_empty())
+            .collect::<Vec<_>>();
+        let _where = if sqls.len() > 1 {
+            let clauses = sqls
+                .iter()
+                .map(|sql| format!("({clause})", clause = sql.where_clauses))
+                .join(&format!(" {operator} "));
+            format!("({clauses})")
+        } else {
+            sqls.iter().map(|sql| sql.where_clauses.clone()).collect()
+        };
+        let _join = sqls
+            .iter()
+            .flat_map(|sql| sql.join_clauses.clone())
+            .collect();
+        let params = sqls.into_iter().flat_map(|sql| sql.params).collect();
+        SQL::new(_where, _join).with_params(params)
+    }
+
+    pub fn and(sqls: Vec<SQL>) -> SQL {
+        SQL::combine(sqls, "AND")
+    }
+
+    pub fn or(sqls: Vec<SQL>) -> SQL {
+        SQL::combine(sqls, "OR")
+    }
+
     pub fn wheres(&self) -> String {
         if self.where_clauses.is_empty() {
             "".to_string()
@@ -66,27 +148,48 @@ pub trait ToSql {
     fn to_sql(&self) -> SQL;
 }
 
-impl ToSql for PowerQuery {
-    fn to_sql(&self) -> SQL {
-        let operator = match self.operator {
-            PowerOperator::LessThan => "<",
-            PowerOperator::LessThanOrEqual => "<=",
-            PowerOperator::NotEqual => "!=",
-            PowerOperator::Colon => "=",
-            PowerOperator::Equal => "=",
-            PowerOperator::GreaterThan => ">",
-            PowerOperator::GreaterThanOrEqual => ">=",
-        }
-        .to_string();
-        let clauses = match &self.operand {
-            PowerOperand::Number(num) => {
-                format!("cards.power{operator}{num}", operator = operator, num = num)
-            }
-            PowerOperand::Tougness => {
-                format!("cards.power{operator}cards.toughness", operator = operator,)
+impl Stat {
+    // Stats are stored as text since they aren't always numbers (*, 1+*, X,
+    // etc). Casting uses the leading number of the value, so * and X are
+    // compared as 0 and 1+* is compared as 1. Cards without the stat are NULL
+    // and never match.
+    fn as_sql(&self, table: &str) -> String {
+        match self {
+            Stat::Power => format!("CAST({table}.power AS REAL)"),
+            Stat::Toughness => format!("CAST({table}.toughness AS REAL)"),
+            Stat::Loyalty => format!("CAST({table}.loyalty AS REAL)"),
+            Stat::Defense => format!("CAST({table}.defense AS REAL)"),
+            Stat::PowerToughness => {
+                format!("(CAST({table}.power AS REAL)+CAST({table}.toughness AS REAL))")
             }
+        }
+    }
+}
+
+impl StatQuery {
+    fn comparison(&self, table: &str) -> String {
+        let operand = match &self.operand {
+            StatOperand::Number(num) => num.to_string(),
+            StatOperand::Stat(stat) => stat.as_sql(table),
         };
+        format!(
+            "{stat}{operator}{operand}",
+            stat = self.stat.as_sql(table),
+            operator = self.operator.as_sql(),
+            operand = operand
+        )
+    }
+}
 
+impl ToSql for StatQuery {
+    // Multi-faced cards keep their stats on their faces, so a card matches if
+    // either it or any of its faces match.
+    fn to_sql(&self) -> SQL {
+        let clauses = format!(
+            "{card} OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND {face})",
+            card = self.comparison("cards"),
+            face = self.comparison("card_faces"),
+        );
         let _where = format!(
             "{negated}({clauses})",
             clauses = clauses,
@@ -96,6 +199,34 @@ impl ToSql for PowerQuery {
     }
 }
 
+impl ToSql for ManaValueQuery {
+    fn to_sql(&self) -> SQL {
+        let clauses = match &self.operand {
+            ManaValueOperand::Number(num) => format!(
+                "cards.cmc{operator}{num}",
+                operator = self.operator.as_sql(),
+                num = num
+            ),
+            // Some funny cards have fractional mana values which are neither
+            // even nor odd.
+            ManaValueOperand::Even | ManaValueOperand::Odd => format!(
+                "cards.cmc=CAST(cards.cmc AS INTEGER) AND CAST(cards.cmc AS INTEGER) % 2 = {remainder}",
+                remainder = if self.operand == ManaValueOperand::Even { 0 } else { 1 }
+            ),
+        };
+        // The operator is already part of the clause for numbers, so only the
+        // parity clauses need to be negated here.
+        let negated = self.operator == NumericOperator::NotEqual
+            && !matches!(self.operand, ManaValueOperand::Number(_));
+        let _where = format!(
+            "{negated}({clauses})",
+            negated = if negated { "NOT " } else { "" },
+            clauses = clauses
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
 impl ColorIdentityQuery {
     fn colors_true(&self) -> Vec<String> {
         self.operand
@@ -261,108 +392,297 @@ impl ToSql for ColorIdentityQuery {
     }
 }
 
+impl ColorCount {
+    // Cards with multiple faces can keep their colors on their faces, so a
+    // color is counted if the card or any of its faces has it.
+    fn as_sql(&self) -> String {
+        match self {
+            ColorCount::Color => {
+                let colors = ColorOperand::all_colors()
+                    .into_iter()
+                    .map(|color| {
+                        format!("(IFNULL(cards.{color}, FALSE) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND card_faces.{color}))")
+                    })
+                    .join(" + ");
+                format!("({colors})")
+            }
+            ColorCount::Identity => "(SELECT COUNT(DISTINCT card_color_identity.color_identity) FROM card_color_identity WHERE card_color_identity.card_id = cards.id)".to_string(),
+        }
+    }
+}
+
+impl ToSql for ColorCountQuery {
+    fn to_sql(&self) -> SQL {
+        // The count is parsed as digits so it's safe to include in the clause.
+        let _where = format!(
+            "({count}{operator}{operand})",
+            count = self.count.as_sql(),
+            operator = self.operator.as_sql(),
+            operand = self.operand
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for IndicatorQuery {
+    fn to_sql(&self) -> SQL {
+        card_or_faces(|table| {
+            SQL::new(
+                format!("{table}.color_indicator IS NOT NULL", table = table),
+                vec![],
+            )
+        })
+    }
+}
+
 impl ToSql for ColorQuery {
     fn to_sql(&self) -> SQL {
-        let all_colors = ColorOperand::all_colors().into_iter();
-        let all_colors_set: HashSet<String> = HashSet::from_iter(all_colors.clone());
-        let colors = HashSet::from_iter(self.operand.as_set());
-        let clauses = match self.operator {
-            ColorOperator::LessThan => {
-                let positive = colors
-                    .iter()
-                    .sorted()
-                    .map(|color| format!("cards.{color}=TRUE", color = color))
-                    .join(" OR ");
-                let not_all_positive = colors
-                    .iter()
-                    .sorted()
-                    .map(|color| format!("cards.{color}=TRUE", color = color))
-                    .join(" AND ");
-                let not_all_positive = format!(
-                    "NOT ({not_all_positive})",
-                    not_all_positive = not_all_positive
-                );
-                let negative = all_colors_set
-                    .difference(&colors)
-                    .sorted()
-                    .map(|c| format!("cards.{color}=FALSE", color = c))
-                    .join(" AND ");
-                format!(
-                    "({positive}) AND ({not_all_positive}) AND ({negative})",
-                    positive = positive,
-                    not_all_positive = not_all_positive,
-                    negative = negative
-                )
-            }
-            // TODO - check this, not positive.
-            ColorOperator::LessThanOrEqual => {
-                let positive = colors
-                    .iter()
-                    .sorted()
-                    .map(|color| format!("cards.{color}=TRUE", color = color))
-                    .join(" OR ");
-                let negative = all_colors_set
-                    .difference(&colors)
-                    .sorted()
-                    .map(|c| format!("cards.{color}=FALSE", color = c))
-                    .join(" AND ");
-                format!(
-                    "({positive}) AND ({negative})",
-                    positive = positive,
-                    negative = negative
-                )
-            }
-            ColorOperator::NotEqual => colors
+        let clauses = color_comparison(&self.operator, &self.operand, |color, has| {
+            format!(
+                "cards.{color}={has}",
+                has = if has { "TRUE" } else { "FALSE" }
+            )
+        });
+        SQL::new(clauses, vec![])
+    }
+}
+
+// Builds the clauses for comparing a set of colors using the `ColorQuery`
+// semantics. `has_color` turns a color and whether it should be present into a
+// clause, which lets anything shaped like a set of colors share this.
+fn color_comparison(
+    operator: &ColorOperator,
+    operand: &ColorOperand,
+    has_color: impl Fn(&str, bool) -> String,
+) -> String {
+    let all_colors = ColorOperand::all_colors().into_iter();
+    let all_colors_set: HashSet<String> = HashSet::from_iter(all_colors.clone());
+    let colors = HashSet::from_iter(operand.as_set());
+    match operator {
+        ColorOperator::LessThan => {
+            let positive = colors
                 .iter()
                 .sorted()
-                .map(|color| format!("cards.{color}=FALSE", color = color))
-                .join(" AND "),
-            ColorOperator::Equal => all_colors
+                .map(|color| has_color(color, true))
+                .join(" OR ");
+            let not_all_positive = colors
+                .iter()
                 .sorted()
-                .map(|color| {
-                    if colors.contains(&color) {
-                        format!("cards.{color}=TRUE", color = color)
-                    } else {
-                        format!("cards.{color}=FALSE", color = color)
-                    }
-                })
-                .join(" AND "),
-            ColorOperator::GreaterThan => {
-                let at_least = colors
-                    .iter()
-                    .sorted()
-                    .map(|color| format!("cards.{color}=TRUE", color = color))
-                    .join(" AND ");
-                let others = all_colors_set
-                    .difference(&colors)
-                    .sorted()
-                    .map(|c| format!("cards.{color}=TRUE", color = c))
-                    .join(" OR ");
-                format!(
-                    "({at_least}) AND ({others})",
-                    at_least = at_least,
-                    others = others
-                )
-            }
-            ColorOperator::GreaterThanOrEqual | ColorOperator::Colon => {
-                let at_least = colors
-                    .iter()
-                    .sorted()
-                    .map(|color| format!("cards.{color}=TRUE", color = color))
-                    .join(" AND ");
-                format!("{at_least}", at_least = at_least)
-            }
-        };
-        let _where = format!("{clauses}", clauses = clauses);
+                .map(|color| has_color(color, true))
+                .join(" AND ");
+            let not_all_positive = format!(
+                "NOT ({not_all_positive})",
+                not_all_positive = not_all_positive
+            );
+            let negative = all_colors_set
+                .difference(&colors)
+                .sorted()
+                .map(|c| has_color(c, false))
+                .join(" AND ");
+            format!(
+                "({positive}) AND ({not_all_positive}) AND ({negative})",
+                positive = positive,
+                not_all_positive = not_all_positive,
+                negative = negative
+            )
+        }
+        // TODO - check this, not positive.
+        ColorOperator::LessThanOrEqual => {
+            let positive = colors
+                .iter()
+                .sorted()
+                .map(|color| has_color(color, true))
+                .join(" OR ");
+            let negative = all_colors_set
+                .difference(&colors)
+                .sorted()
+                .map(|c| has_color(c, false))
+                .join(" AND ");
+            format!(
+                "({positive}) AND ({negative})",
+                positive = positive,
+                negative = negative
+            )
+        }
+        ColorOperator::NotEqual => colors
+            .iter()
+            .sorted()
+            .map(|color| has_color(color, false))
+            .join(" AND "),
+        ColorOperator::Equal => all_colors
+            .sorted()
+            .map(|color| {
+                if colors.contains(&color) {
+                    has_color(&color, true)
+                } else {
+                    has_color(&color, false)
+                }
+            })
+            .join(" AND "),
+        ColorOperator::GreaterThan => {
+            let at_least = colors
+                .iter()
+                .sorted()
+                .map(|color| has_color(color, true))
+                .join(" AND ");
+            let others = all_colors_set
+                .difference(&colors)
+                .sorted()
+                .map(|c| has_color(c, true))
+                .join(" OR ");
+            format!(
+                "({at_least}) AND ({others})",
+                at_least = at_least,
+                others = others
+            )
+        }
+        ColorOperator::GreaterThanOrEqual | ColorOperator::Colon => {
+            let at_least = colors
+                .iter()
+                .sorted()
+                .map(|color| has_color(color, true))
+                .join(" AND ");
+            format!("{at_least}", at_least = at_least)
+        }
+    }
+}
+
+// Wraps user provided text so it can be bound to a `LIKE ? ESCAPE '\'`
+// placeholder. `%` and `_` are escaped so they match themselves instead of
+// acting as wildcards.
+fn like_param(text: &str) -> Value {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    Value::Text(format!("%{escaped}%", escaped = escaped))
+}
+
+// Matches a column against a plain substring with `LIKE`, or against a regex
+// with the `REGEXP` function registered in `Database::connection`.
+fn text_operand_sql(column: &str, operand: &TextOperand) -> SQL {
+    match operand {
+        TextOperand::Text(text) => {
+            let _where = format!("({column} LIKE ? ESCAPE '\\')", column = column);
+            SQL::new(_where, vec![]).with_params(vec![like_param(text)])
+        }
+        TextOperand::Regex(regex) => {
+            let _where = format!("({column} REGEXP ?)", column = column);
+            SQL::new(_where, vec![]).with_params(vec![Value::Text(regex.clone())])
+        }
+    }
+}
+
+// Printed names and text are only set on prints in other languages. Checking
+// for them first keeps a missing value from turning the whole clause NULL,
+// which would otherwise stop a negated search from matching anything.
+fn printed_sql(column: &str, clause: SQL) -> SQL {
+    let _where = format!(
+        "({column} IS NOT NULL AND {clause})",
+        column = column,
+        clause = clause.where_clauses
+    );
+    SQL::new(_where, clause.join_clauses).with_params(clause.params)
+}
+
+// Reversible cards only have an oracle id on their faces, so the first face's
+// is used for them.
+fn oracle_id_sql(table: &str) -> String {
+    format!("IFNULL({table}.oracle_id, (SELECT card_faces.oracle_id FROM card_faces WHERE card_faces.card_id = {table}.id ORDER BY card_faces.face_index LIMIT 1))")
+}
+
+// Whether `print` is a print of the same card as `cards`. Comparing the column
+// first lets the lookup use the `cards(oracle_id)` index. Only reversible
+// prints need their faces checked.
+fn same_card_sql(print: &str) -> String {
+    format!(
+        "({print}.oracle_id = {oracle_id} OR ({print}.oracle_id IS NULL AND {print_oracle_id} = {oracle_id}))",
+        print = print,
+        oracle_id = oracle_id_sql("cards"),
+        print_oracle_id = oracle_id_sql(print),
+    )
+}
+
+// Multi-faced cards keep most of their text on their faces, so a card matches
+// if either it or any of its faces match. `clause` is given the table to build
+// the clause against.
+fn card_or_faces(clause: impl Fn(&str) -> SQL) -> SQL {
+    let card = clause("cards");
+    let face = clause("card_faces");
+    let _where = format!(
+        "({card} OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND {face}))",
+        card = card.where_clauses,
+        face = face.where_clauses,
+    );
+    SQL::new(_where, vec![]).with_params(card.params.into_iter().chain(face.params).collect())
+}
+
+impl ToSql for ArtistQuery {
+    fn to_sql(&self) -> SQL {
+        card_or_faces(|table| {
+            let _where = format!("{table}.artist LIKE ? ESCAPE '\\'", table = table);
+            SQL::new(_where, vec![]).with_params(vec![like_param(&self.artist)])
+        })
+    }
+}
+
+impl ToSql for ArtistsQuery {
+    fn to_sql(&self) -> SQL {
+        // Single faced cards only have an artist on the card itself. The count
+        // is parsed as digits so it's safe to include in the clause.
+        let _where = format!(
+            "(COALESCE(NULLIF((SELECT COUNT(DISTINCT card_faces.artist) FROM card_faces WHERE card_faces.card_id = cards.id), 0), cards.artist IS NOT NULL){operator}{count})",
+            operator = self.operator.as_sql(),
+            count = self.count,
+        );
         SQL::new(_where, vec![])
     }
 }
 
+impl ToSql for FlavorQuery {
+    fn to_sql(&self) -> SQL {
+        card_or_faces(|table| text_operand_sql(&format!("{table}.flavor_text"), &self.flavor_text))
+    }
+}
+
+impl ToSql for WatermarkQuery {
+    fn to_sql(&self) -> SQL {
+        card_or_faces(|table| match self {
+            WatermarkQuery::Named(watermark) => {
+                let _where = format!("{table}.watermark = ? COLLATE NOCASE", table = table);
+                SQL::new(_where, vec![]).with_params(vec![Value::Text(watermark.clone())])
+            }
+            WatermarkQuery::Any => SQL::new(
+                format!("{table}.watermark IS NOT NULL", table = table),
+                vec![],
+            ),
+        })
+    }
+}
+
+// Names and rules text also match what's printed on cards in other languages,
+// which is only set on those prints. This lets a foreign card be looked up by
+// what's written on it along with lang: or lang:any.
 impl ToSql for Name {
     fn to_sql(&self) -> SQL {
-        let like = format!("cards.name LIKE '%{name}%'", name = self.text);
-        let _where = format!("({like})", like = like);
-        SQL::new(_where, vec![])
+        match self {
+            Name::Contains(text) => SQL::or(vec![
+                text_operand_sql("cards.name", text),
+                printed_sql(
+                    "cards.printed_name",
+                    text_operand_sql("cards.printed_name", text),
+                ),
+            ]),
+            Name::Exact(name) if name.is_empty() => SQL::default(),
+            Name::Exact(name) => card_or_faces(|table| {
+                let _where = format!(
+                    "({table}.name = ? COLLATE NOCASE OR ({table}.printed_name IS NOT NULL AND {table}.printed_name = ? COLLATE NOCASE))",
+                    table = table
+                );
+                SQL::new(_where, vec![])
+                    .with_params(vec![Value::Text(name.clone()), Value::Text(name.clone())])
+            }),
+        }
     }
 }
 
@@ -370,24 +690,101 @@ impl ToSql for SearchKeyword {
     fn to_sql(&self) -> SQL {
         match self {
             SearchKeyword::ColorQuery(color) => color.to_sql(),
-            SearchKeyword::PowerQuery(power) => power.to_sql(),
+            SearchKeyword::StatQuery(stat) => stat.to_sql(),
             SearchKeyword::Name(name) => name.to_sql(),
             SearchKeyword::TypeLineQuery(type_line) => type_line.to_sql(),
             SearchKeyword::Keyword(kw) => kw.to_sql(),
             SearchKeyword::OracleQuery(oq) => oq.to_sql(),
             SearchKeyword::ColorIdentityQuery(ciq) => ciq.to_sql(),
+            SearchKeyword::ManaValueQuery(mvq) => mvq.to_sql(),
+            SearchKeyword::LegalityQuery(lq) => lq.to_sql(),
+            SearchKeyword::SetQuery(sq) => sq.to_sql(),
+            SearchKeyword::SetTypeQuery(stq) => stq.to_sql(),
+            SearchKeyword::CollectorNumberQuery(cnq) => cnq.to_sql(),
+            SearchKeyword::RarityQuery(rq) => rq.to_sql(),
+            SearchKeyword::ManaCostQuery(mcq) => mcq.to_sql(),
+            SearchKeyword::ProducesQuery(pq) => pq.to_sql(),
+            SearchKeyword::DevotionQuery(dq) => dq.to_sql(),
+            SearchKeyword::ArtistQuery(aq) => aq.to_sql(),
+            SearchKeyword::ArtistsQuery(aq) => aq.to_sql(),
+            SearchKeyword::FlavorQuery(fq) => fq.to_sql(),
+            SearchKeyword::WatermarkQuery(wq) => wq.to_sql(),
+            SearchKeyword::IsQuery(iq) => iq.to_sql(),
+            SearchKeyword::YearQuery(yq) => yq.to_sql(),
+            SearchKeyword::DateQuery(dq) => dq.to_sql(),
+            SearchKeyword::PriceQuery(pq) => pq.to_sql(),
+            SearchKeyword::CheapestQuery(cq) => cq.to_sql(),
+            SearchKeyword::LanguageQuery(lq) => lq.to_sql(),
+            SearchKeyword::InQuery(iq) => iq.to_sql(),
+            SearchKeyword::PrintsQuery(pq) => pq.to_sql(),
+            SearchKeyword::GameQuery(gq) => gq.to_sql(),
+            SearchKeyword::IdQuery(iq) => iq.to_sql(),
+            SearchKeyword::BorderQuery(bq) => bq.to_sql(),
+            SearchKeyword::FrameQuery(fq) => fq.to_sql(),
+            SearchKeyword::StampQuery(sq) => sq.to_sql(),
+            SearchKeyword::ShortcutQuery(sq) => sq.to_sql(),
+            SearchKeyword::IncludeQuery(iq) => iq.to_sql(),
+            SearchKeyword::OrderQuery(oq) => oq.to_sql(),
+            SearchKeyword::DirectionQuery(dq) => dq.to_sql(),
+            SearchKeyword::UniqueQuery(uq) => uq.to_sql(),
+            SearchKeyword::ColorCountQuery(ccq) => ccq.to_sql(),
+            SearchKeyword::IndicatorQuery(iq) => iq.to_sql(),
         }
     }
 }
 
+// o: leaves out reminder text, which fo: includes. Oracle text uses the card's
+// name where Scryfall writes ~ or CARDNAME, so when the search uses either,
+// the card's own name in its text is swapped for ~ before comparing.
+fn rules_text_sql(text: &str, name: &str, operand: &TextOperand, full_text: bool) -> SQL {
+    let operand = match operand {
+        TextOperand::Text(text) => TextOperand::Text(CARDNAME.replace_all(text, "~").to_string()),
+        TextOperand::Regex(regex) => {
+            TextOperand::Regex(CARDNAME.replace_all(regex, "~").to_string())
+        }
+    };
+    // A missing text is treated as empty so that negating the search still
+    // matches cards without any.
+    let column = if full_text {
+        format!("IFNULL({text}, '')", text = text)
+    } else {
+        format!("strip_reminder_text({text})", text = text)
+    };
+    let column = match &operand {
+        TextOperand::Text(text) | TextOperand::Regex(text) if text.contains('~') => {
+            format!(
+                "REPLACE({column}, {name}, '~')",
+                column = column,
+                name = name
+            )
+        }
+        _ => column,
+    };
+    text_operand_sql(&column, &operand)
+}
+
+// Cards with multiple faces usually only have rules text on their faces.
 impl ToSql for OracleQuery {
     fn to_sql(&self) -> SQL {
-        let like = format!(
-            "cards.oracle_text LIKE '%{oracle_text}%'",
-            oracle_text = self.oracle_text
-        );
-        let _where = format!("({like})", like = like);
-        SQL::new(_where, vec![])
+        card_or_faces(|table| {
+            SQL::or(vec![
+                rules_text_sql(
+                    &format!("{table}.oracle_text", table = table),
+                    &format!("{table}.name", table = table),
+                    &self.oracle_text,
+                    self.full_text,
+                ),
+                printed_sql(
+                    &format!("{table}.printed_text", table = table),
+                    rules_text_sql(
+                        &format!("{table}.printed_text", table = table),
+                        &format!("IFNULL({table}.printed_name, {table}.name)", table = table),
+                        &self.oracle_text,
+                        self.full_text,
+                    ),
+                ),
+            ])
+        })
     }
 }
 
@@ -398,262 +795,2436 @@ impl ToSql for KeywordQuery {
             "LEFT JOIN card_keywords {alias} ON cards.id = {alias}.card_id",
             alias = alias,
         )];
+        let _where = format!("{alias}.keyword LIKE ? ESCAPE '\\'", alias = alias);
+        SQL::new(_where, _join).with_params(vec![like_param(&self.keyword)])
+    }
+}
+
+impl ToSql for LegalityQuery {
+    fn to_sql(&self) -> SQL {
+        let alias = generate_table_name();
+        // The format comes from a fixed list rather than user text, so it's
+        // safe to include in the join. Joining on it keeps this to one row per
+        // card which means negating the legality works as expected.
+        let _join = vec![format!(
+            "LEFT JOIN card_legalities {alias} ON cards.id = {alias}.card_id AND {alias}.format = '{format}'",
+            alias = alias,
+            format = self.format,
+        )];
+        let legalities = match self.legality {
+            Legality::Legal => "'legal', 'restricted'",
+            Legality::Banned => "'banned'",
+            Legality::Restricted => "'restricted'",
+        };
         let _where = format!(
-            "{alias}.keyword LIKE '%{keyword}%'",
-            keyword = self.keyword,
-            alias = alias
+            "{alias}.legality IN ({legalities})",
+            alias = alias,
+            legalities = legalities
         );
         SQL::new(_where, _join)
     }
 }
 
-impl ToSql for ParsedSearch {
+impl ToSql for SetQuery {
     fn to_sql(&self) -> SQL {
-        match self {
-            ParsedSearch::Keyword(keyword) => keyword.to_sql(),
-            ParsedSearch::And(operands) | ParsedSearch::Or(operands) => {
-                if operands.len() == 1 {
-                    return operands[0].to_sql();
-                }
-                let sqls = operands
-                    .iter()
-                    .map(|query| query.to_sql())
-                    .collect::<Vec<_>>();
-                let _where = sqls
-                    .iter()
-                    .map(|sql| sql.where_clauses.clone())
-                    .collect::<Vec<_>>()
-                    .join(&format!(
-                        " {} ",
-                        if matches!(self, ParsedSearch::And(_)) {
-                            "AND"
-                        } else {
-                            "OR"
-                        }
-                    ));
-                let _join = sqls
-                    .iter()
-                    .map(|sql| sql.join_clauses.clone())
-                    .flatten()
-                    .collect();
-                SQL::new(_where, _join)
+        let _where = "(cards.set_code = ?)".to_string();
+        SQL::new(_where, vec![]).with_params(vec![Value::Text(self.set_code.clone())])
+    }
+}
+
+impl ToSql for SetTypeQuery {
+    fn to_sql(&self) -> SQL {
+        let _where = "(cards.set_type = ?)".to_string();
+        SQL::new(_where, vec![]).with_params(vec![Value::Text(self.set_type.clone())])
+    }
+}
+
+impl ToSql for CollectorNumberQuery {
+    fn to_sql(&self) -> SQL {
+        let _where = match self.operator {
+            NumericOperator::Colon | NumericOperator::Equal | NumericOperator::NotEqual => {
+                format!(
+                    "(cards.collector_number{operator}?)",
+                    operator = self.operator.as_sql()
+                )
             }
-            ParsedSearch::Negated(negated, search) => {
-                let sql = search.to_sql();
-                let _where = if *negated {
-                    format!(" NOT ({search})", search = sql.where_clauses)
-                } else {
-                    sql.where_clauses
-                };
-                SQL::new(_where, sql.join_clauses)
+            _ => format!(
+                "(CAST(cards.collector_number AS INTEGER){operator}CAST(? AS INTEGER))",
+                operator = self.operator.as_sql()
+            ),
+        };
+        SQL::new(_where, vec![]).with_params(vec![Value::Text(self.operand.clone())])
+    }
+}
+
+impl ToSql for LanguageQuery {
+    fn to_sql(&self) -> SQL {
+        match self {
+            LanguageQuery::Language(language) => {
+                let _where = "(cards.lang = ?)".to_string();
+                SQL::new(_where, vec![]).with_params(vec![Value::Text(language.clone())])
             }
+            // lang:any only turns off the English default, so like include:
+            // it doesn't add a clause.
+            LanguageQuery::Any => SQL::default(),
         }
     }
 }
 
-impl ToSql for TypeLineQuery {
+impl ToSql for InQuery {
     fn to_sql(&self) -> SQL {
-        // TODO - I need to clean up this whole thing since this allows for the
-        // potential of sql injection. Instead of returning String, I need to
-        // return some sort of IntoClause trait or something similar that can
-        // include the information on any parameters that need to be passed in.
-        if self.operand.is_empty() {
-            SQL::default()
-        } else {
-            let clauses = format!("cards.type_line LIKE '%{}%'", self.operand);
-            let _where = format!("({clauses})", clauses = clauses);
-            SQL::new(_where, vec![])
+        match self {
+            InQuery::Language(language) => {
+                let _where = format!(
+                    "(EXISTS (SELECT 1 FROM cards AS prints WHERE {same_card} AND prints.lang = ?))",
+                    same_card = same_card_sql("prints"),
+                );
+                SQL::new(_where, vec![]).with_params(vec![Value::Text(language.clone())])
+            }
+            // Games come from a fixed set so they're safe to include in the
+            // clause.
+            InQuery::Game(game) => {
+                let _where = format!(
+                    "(EXISTS (SELECT 1 FROM cards AS prints JOIN card_games ON card_games.card_id = prints.id WHERE {same_card} AND card_games.game = '{game}'))",
+                    same_card = same_card_sql("prints"),
+                );
+                SQL::new(_where, vec![])
+            }
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::search::{self};
+impl ToSql for GameQuery {
+    fn to_sql(&self) -> SQL {
+        // Games come from a fixed set so they're safe to include in the clause.
+        let _where = format!(
+            "(EXISTS (SELECT 1 FROM card_games WHERE card_games.card_id = cards.id AND card_games.game = '{game}'))",
+            game = self.game,
+        );
+        SQL::new(_where, vec![])
+    }
+}
 
-    // #[test]
-    // pub fn equals_esper() {
-    //     let search = search::search("c=ESPER").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected =
-    //         "(cards.B=TRUE AND cards.G=FALSE AND cards.R=FALSE AND cards.U=TRUE AND cards.W=TRUE)";
-    //     assert_eq!(actual, expected)
-    // }
+impl ToSql for IdQuery {
+    fn to_sql(&self) -> SQL {
+        let column = match self.game_id {
+            GameId::Arena => "arena_id",
+            GameId::Mtgo => "mtgo_id",
+        };
+        // The id is parsed as digits so it's safe to include in the clause.
+        let _where = format!("(cards.{column} = {id})", id = self.id);
+        SQL::new(_where, vec![])
+    }
+}
 
-    // #[test]
-    // pub fn not_equals_esper() {
-    //     let search = search::search("c!=ESPER").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "(cards.B=FALSE AND cards.U=FALSE AND cards.W=FALSE)";
-    //     assert_eq!(actual, expected)
-    // }
+impl ToSql for BorderQuery {
+    fn to_sql(&self) -> SQL {
+        let _where = "(cards.border_color = ?)".to_string();
+        SQL::new(_where, vec![]).with_params(vec![Value::Text(self.border.clone())])
+    }
+}
 
-    // #[test]
-    // pub fn not_esper_and_not_golgari() {
-    //     let search = search::search("c!=ESPER c!=GOLGARI").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "(cards.B=FALSE AND cards.U=FALSE AND cards.W=FALSE) AND (cards.B=FALSE AND cards.G=FALSE)";
-    //     assert_eq!(actual, expected);
-    // }
+// A frame is either the frame edition or one of the print's frame effects.
+impl ToSql for FrameQuery {
+    fn to_sql(&self) -> SQL {
+        let _where = "(cards.frame = ? OR EXISTS (SELECT 1 FROM card_frame_effects WHERE card_frame_effects.card_id = cards.id AND card_frame_effects.frame_effect = ?))".to_string();
+        let frame = Value::Text(self.frame.clone());
+        SQL::new(_where, vec![]).with_params(vec![frame.clone(), frame])
+    }
+}
 
-    // #[test]
-    // pub fn other_greater_or_equal_esper() {
-    //     let search = search::search("c>=ESPER").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "(cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
-    //     assert_eq!(actual, expected)
-    // }
+impl ToSql for StampQuery {
+    fn to_sql(&self) -> SQL {
+        let _where = "(cards.security_stamp = ?)".to_string();
+        SQL::new(_where, vec![]).with_params(vec![Value::Text(self.stamp.clone())])
+    }
+}
 
-    // #[test]
-    // pub fn less_than_or_equal_esper() {
-    //     let search = search::search("c<=ESPER").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "((cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (cards.G=FALSE AND cards.R=FALSE))";
-    //     assert_eq!(actual, expected)
-    // }
+// Extras are the cards that aren't really part of the game, like tokens,
+// schemes and art series cards. The layouts and set types are static so it's
+// safe to include them in the clause.
+const EXTRA_LAYOUTS: &[&str] = &[
+    "art_series",
+    "double_faced_token",
+    "emblem",
+    "planar",
+    "scheme",
+    "token",
+    "vanguard",
+];
+const EXTRA_SET_TYPES: &[&str] = &["memorabilia", "token"];
 
-    // #[test]
-    // pub fn other_less_than_or_equal_esper() {
-    //     let search = search::search("c:ESPER").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "(cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
-    //     assert_eq!(actual, expected)
-    // }
+// Extras are shown when a search asks for them with include:extras, looks in
+// a particular set, or names one of their types. Negated keywords don't count,
+// since -t:token isn't asking for any extras.
+fn shows_extras(keyword: &SearchKeyword) -> bool {
+    match keyword {
+        SearchKeyword::IncludeQuery(_)
+        | SearchKeyword::SetQuery(_)
+        | SearchKeyword::SetTypeQuery(_) => true,
+        SearchKeyword::TypeLineQuery(TypeLineQuery {
+            operand,
+            negated: false,
+        }) => match operand {
+            TextOperand::Text(text) | TextOperand::Regex(text) => EXTRA_TYPES.is_match(text),
+        },
+        _ => false,
+    }
+}
+
+// Extras are hidden by default. Cards without a layout or set type aren't
+// extras, so NULLs are treated as empty rather than dropping the card.
+fn hide_extras() -> SQL {
+    let quoted = |values: &[&str]| values.iter().map(|value| format!("'{value}'")).join(", ");
+    let _where = format!(
+        "(IFNULL(cards.layout, '') NOT IN ({layouts}) AND IFNULL(cards.set_type, '') NOT IN ({set_types}))",
+        layouts = quoted(EXTRA_LAYOUTS),
+        set_types = quoted(EXTRA_SET_TYPES),
+    );
+    SQL::new(_where, vec![])
+}
+
+impl ToSql for Search {
+    fn to_sql(&self) -> SQL {
+        let Some(parsed_search) = &self.parsed_search else {
+            return SQL::default();
+        };
+        let mut sqls = vec![parsed_search.to_sql()];
+        // A search only includes English cards unless it asks for a language.
+        if !parsed_search.any_keyword(&|keyword| matches!(keyword, SearchKeyword::LanguageQuery(_)))
+        {
+            sqls.push(SQL::new("(cards.lang = 'en')".to_string(), vec![]));
+        }
+        if !parsed_search.any_wanted_keyword(&shows_extras) {
+            sqls.push(hide_extras());
+        }
+        SQL::and(sqls)
+    }
+}
+
+// Including extras only changes the defaults for the search, so it doesn't
+// add a clause of its own. That keeps it from matching everything in an `OR`,
+// or nothing when negated.
+impl ToSql for IncludeQuery {
+    fn to_sql(&self) -> SQL {
+        SQL::default()
+    }
+}
+
+// Like include:, ordering is an option for the whole search and doesn't add a
+// clause.
+impl ToSql for OrderQuery {
+    fn to_sql(&self) -> SQL {
+        SQL::default()
+    }
+}
+
+impl ToSql for DirectionQuery {
+    fn to_sql(&self) -> SQL {
+        SQL::default()
+    }
+}
+
+impl ToSql for UniqueQuery {
+    fn to_sql(&self) -> SQL {
+        SQL::default()
+    }
+}
+
+// When several prints are grouped together, the one shown is the print with
+// the largest value for this: English prints first, then prints that aren't
+// promos, then the newest. The id breaks any remaining ties so the same print
+// is always picked.
+pub const PREFERRED_PRINT: &str = "printf('%d%d%s%s', IFNULL(cards.lang = 'en', 0), NOT IFNULL(cards.promo, 0), IFNULL(cards.released_at, ''), cards.id)";
+
+impl Unique {
+    // What prints are grouped by. Cards without an oracle id or artwork are
+    // never grouped with anything else.
+    fn group_by(&self) -> String {
+        match self {
+            Unique::Cards => format!(
+                "IFNULL({oracle_id}, cards.id)",
+                oracle_id = oracle_id_sql("cards")
+            ),
+            Unique::Prints => "cards.id".to_string(),
+            Unique::Art => "COALESCE(cards.illustration_id, (SELECT card_faces.illustration_id FROM card_faces WHERE card_faces.card_id = cards.id AND card_faces.illustration_id IS NOT NULL ORDER BY card_faces.face_index LIMIT 1), cards.id)".to_string(),
+        }
+    }
+}
+
+// A card's stat, falling back to its first face that has one.
+fn face_stat_sql(stat: &str) -> String {
+    format!(
+        "CAST(COALESCE(cards.{stat}, (SELECT card_faces.{stat} FROM card_faces WHERE card_faces.card_id = cards.id AND card_faces.{stat} IS NOT NULL ORDER BY card_faces.face_index LIMIT 1)) AS REAL)"
+    )
+}
+
+impl SortOrder {
+    // The expressions results are sorted by, most significant first. Results
+    // are grouped by name, so these are read from the print the group's id
+    // comes from.
+    fn sort_keys(&self) -> Vec<String> {
+        match self {
+            SortOrder::Name => vec!["cards.name".to_string()],
+            SortOrder::Cmc => vec!["cards.cmc".to_string()],
+            SortOrder::Power => vec![face_stat_sql("power")],
+            SortOrder::Toughness => vec![face_stat_sql("toughness")],
+            SortOrder::Released => vec!["cards.released_at".to_string()],
+            SortOrder::Rarity => {
+                let rarities = Rarity::all()
+                    .into_iter()
+                    .map(|rarity| format!("WHEN '{rarity}' THEN {order}", order = rarity.order()))
+                    .join(" ");
+                vec![format!("CASE cards.rarity {rarities} END")]
+            }
+            // Like Scryfall, mono-colored cards come first in WUBRG order,
+            // then multicolored cards, then colorless cards.
+            SortOrder::Color => {
+                let colors = ["W", "U", "B", "R", "G"];
+                let count = colors
+                    .iter()
+                    .map(|color| format!("IFNULL(cards.{color}, 0)"))
+                    .join(" + ");
+                let mono = colors
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, color)| format!("WHEN cards.{color} THEN {idx}"))
+                    .join(" ");
+                vec![format!(
+                    "CASE WHEN {count} = 0 THEN 6 WHEN {count} > 1 THEN 5 {mono} END"
+                )]
+            }
+            SortOrder::Edhrec => vec!["cards.edhrec_rank".to_string()],
+            SortOrder::Usd => vec![Currency::Usd.price_sql("cards")],
+            SortOrder::Set => vec![
+                "cards.set_code".to_string(),
+                "CAST(cards.collector_number AS INTEGER)".to_string(),
+                "cards.collector_number".to_string(),
+            ],
+        }
+    }
+}
+
+impl Direction {
+    fn as_sql(&self) -> &str {
+        match self {
+            Direction::Asc => "ASC",
+            Direction::Desc => "DESC",
+        }
+    }
+}
+
+impl Search {
+    // Searches show one print of each card unless they use unique:.
+    pub fn group_by(&self) -> String {
+        let find_unique = |keyword: &SearchKeyword| match keyword {
+            SearchKeyword::UniqueQuery(unique_query) => Some(unique_query.unique),
+            _ => None,
+        };
+        self.parsed_search
+            .as_ref()
+            .and_then(|parsed_search| parsed_search.find_keyword(&find_unique))
+            .unwrap_or_default()
+            .group_by()
+    }
+
+    // Searches are sorted by name unless they use order: or direction:.
+    // Cards without a value to sort by always come last, and ties are broken
+    // by name and then id so that paging through the results with an offset
+    // always sees them in the same order.
+    pub fn order_by(&self) -> String {
+        let find_order = |keyword: &SearchKeyword| match keyword {
+            SearchKeyword::OrderQuery(order_query) => Some(order_query.order),
+            _ => None,
+        };
+        let find_direction = |keyword: &SearchKeyword| match keyword {
+            SearchKeyword::DirectionQuery(direction_query) => Some(direction_query.direction),
+            _ => None,
+        };
+        let order = self
+            .parsed_search
+            .as_ref()
+            .and_then(|parsed_search| parsed_search.find_keyword(&find_order))
+            .unwrap_or(SortOrder::Name);
+        let direction = self
+            .parsed_search
+            .as_ref()
+            .and_then(|parsed_search| parsed_search.find_keyword(&find_direction))
+            .unwrap_or_default();
+        order
+            .sort_keys()
+            .into_iter()
+            .map(|key| {
+                format!(
+                    "({key}) IS NULL, {key} {direction}",
+                    direction = direction.as_sql()
+                )
+            })
+            .chain(["cards.name ASC".to_string(), "cards.id ASC".to_string()])
+            .join(", ")
+    }
+}
+
+impl PrintCount {
+    fn column(&self) -> &str {
+        match self {
+            PrintCount::Prints => "prints",
+            PrintCount::Sets => "sets",
+            PrintCount::PaperPrints => "paper_prints",
+            PrintCount::PaperSets => "paper_sets",
+        }
+    }
+
+    // Print counts are looked up by oracle id in the table update-database
+    // builds once every card has been added.
+    fn as_sql(&self) -> String {
+        format!(
+            "(SELECT oracle_prints.{column} FROM oracle_prints WHERE oracle_prints.oracle_id = {oracle_id})",
+            column = self.column(),
+            oracle_id = oracle_id_sql("cards"),
+        )
+    }
+}
+
+impl ToSql for PrintsQuery {
+    fn to_sql(&self) -> SQL {
+        // The count is parsed as digits so it's safe to include in the clause.
+        let _where = format!(
+            "({count}{operator}{operand})",
+            count = self.count.as_sql(),
+            operator = self.operator.as_sql(),
+            operand = self.operand,
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for YearQuery {
+    fn to_sql(&self) -> SQL {
+        // The year is parsed as digits so it's safe to include in the clause.
+        let _where = format!(
+            "(CAST(SUBSTR(cards.released_at, 1, 4) AS INTEGER){operator}{year})",
+            operator = self.operator.as_sql(),
+            year = self.year,
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for DateQuery {
+    fn to_sql(&self) -> SQL {
+        // Release dates are stored as ISO dates, so they compare correctly as
+        // text. A set stands in for the date its first cards were released.
+        let (date, param) = match &self.operand {
+            DateOperand::Date(date) => ("?", date.to_string()),
+            DateOperand::Set(set_code) => (
+                "(SELECT MIN(sets.released_at) FROM cards AS sets WHERE sets.set_code = ?)",
+                set_code.clone(),
+            ),
+        };
+        let _where = format!(
+            "(cards.released_at{operator}{date})",
+            operator = self.operator.as_sql(),
+        );
+        SQL::new(_where, vec![]).with_params(vec![Value::Text(param)])
+    }
+}
+
+impl Currency {
+    // The price of a print in the currency. A print's price is its nonfoil
+    // price, falling back to its foil prices for prints that only come in
+    // foil. Prints without a price are NULL and never match. This is also
+    // what prints are sorted by.
+    pub fn price_sql(&self, table: &str) -> String {
+        let price = match self {
+            Currency::Usd => {
+                "COALESCE(card_prices.usd, card_prices.usd_foil, card_prices.usd_etched)"
+            }
+            Currency::Eur => "COALESCE(card_prices.eur, card_prices.eur_foil)",
+            Currency::Tix => "card_prices.tix",
+        };
+        format!("(SELECT {price} FROM card_prices WHERE card_prices.card_id = {table}.id)")
+    }
+}
+
+impl ToSql for PriceQuery {
+    fn to_sql(&self) -> SQL {
+        // The price is parsed as a number so it's safe to include in the
+        // clause.
+        let _where = format!(
+            "({price}{operator}{operand})",
+            price = self.currency.price_sql("cards"),
+            operator = self.operator.as_sql(),
+            operand = self.price,
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for CheapestQuery {
+    fn to_sql(&self) -> SQL {
+        let _where = format!(
+            "({price} = (SELECT MIN({print_price}) FROM cards AS prints WHERE {same_card}))",
+            price = self.currency.price_sql("cards"),
+            print_price = self.currency.price_sql("prints"),
+            same_card = same_card_sql("prints"),
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for RarityQuery {
+    fn to_sql(&self) -> SQL {
+        let rarities = Rarity::all()
+            .into_iter()
+            .filter(|rarity| self.matches(rarity))
+            .map(|rarity| format!("'{rarity}'"))
+            .join(", ");
+        let _where = format!("(cards.rarity IN ({rarities}))");
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for ParsedSearch {
+    fn to_sql(&self) -> SQL {
+        match self {
+            ParsedSearch::Keyword(keyword) => keyword.to_sql(),
+            ParsedSearch::And(operands) | ParsedSearch::Or(operands) => {
+                if operands.len() == 1 {
+                    return operands[0].to_sql();
+                }
+                // Keywords without anything to search for, like an empty t:,
+                // don't add a clause.
+                let sqls = operands.iter().map(|query| query.to_sql()).collect();
+                if matches!(self, ParsedSearch::And(_)) {
+                    SQL::and(sqls)
+                } else {
+                    SQL::or(sqls)
+                }
+            }
+            ParsedSearch::Negated(negated, search) => {
+                let sql = search.to_sql();
+                let _where = if *negated && !sql.where_clauses.is_empty() {
+                    format!(" NOT ({search})", search = sql.where_clauses)
+                } else {
+                    sql.where_clauses
+                };
+                SQL::new(_where, sql.join_clauses).with_params(sql.params)
+            }
+        }
+    }
+}
+
+// The mana cost functions are registered in `Database::connection`. Cards
+// with multiple faces only have their costs on the faces, so those are checked
+// too.
+impl ToSql for ManaCostQuery {
+    fn to_sql(&self) -> SQL {
+        // The operator comes from a fixed set so it's safe to include in the
+        // clause. The query cost is normalized and bound as a param.
+        let _where = format!(
+            "(mana_cost_compare(cards.mana_cost, ?, '{operator}') OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND mana_cost_compare(card_faces.mana_cost, ?, '{operator}')))",
+            operator = self.operator,
+        );
+        let operand = Value::Text(self.operand.to_string());
+        SQL::new(_where, vec![]).with_params(vec![operand.clone(), operand])
+    }
+}
+
+impl ToSql for ManaSymbolKind {
+    fn to_sql(&self) -> SQL {
+        let _where = format!(
+            "(mana_cost_has(cards.mana_cost, '{kind}') OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND mana_cost_has(card_faces.mana_cost, '{kind}')))",
+            kind = self,
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for DevotionQuery {
+    fn to_sql(&self) -> SQL {
+        // The colors and count come from the parsed symbols rather than user
+        // text so they're safe to include in the clause.
+        let devotion = |column: &str| {
+            format!(
+                "mana_cost_devotion({column}, '{colors}'){operator}{count}",
+                column = column,
+                colors = self.colors(),
+                operator = self.operator.as_sql(),
+                count = self.count,
+            )
+        };
+        let _where = format!(
+            "({cards} OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND {faces}))",
+            cards = devotion("cards.mana_cost"),
+            faces = devotion("card_faces.mana_cost"),
+        );
+        SQL::new(_where, vec![])
+    }
+}
+
+impl ToSql for ProducesQuery {
+    fn to_sql(&self) -> SQL {
+        let clauses = color_comparison(&self.operator, &self.operand, |color, has| {
+            format!(
+                "{not}EXISTS (SELECT 1 FROM card_produced_mana WHERE card_produced_mana.card_id = cards.id AND card_produced_mana.produced_mana = '{color}')",
+                not = if has { "" } else { "NOT " },
+                color = color,
+            )
+        });
+        SQL::new(clauses, vec![])
+    }
+}
+
+// Layouts come from a fixed list so they're safe to include in the clause.
+fn layout_in(layouts: &[&str]) -> SQL {
+    let layouts = layouts
+        .iter()
+        .map(|layout| format!("'{layout}'", layout = layout))
+        .join(", ");
+    SQL::new(format!("(cards.layout IN ({layouts}))"), vec![])
+}
+
+// Checks whether the type line has any of the given types. The types are
+// static so it's safe to include them in the clause.
+fn type_line_has_any(types: &[&str]) -> String {
+    let types = types
+        .iter()
+        .map(|_type| format!("cards.type_line LIKE '%{_type}%'", _type = _type))
+        .join(" OR ");
+    format!("({types})")
+}
+
+// Whether the print is available in a finish. The finishes are static so it's
+// safe to include them in the clause.
+fn has_finish(finish: &str) -> SQL {
+    let _where = format!("(EXISTS (SELECT 1 FROM card_finishes WHERE card_finishes.card_id = cards.id AND card_finishes.finish = '{finish}'))");
+    SQL::new(_where, vec![])
+}
+
+// Whether the card has rules text once the reminder text is removed. Cards
+// with multiple faces keep their rules text on the faces.
+const HAS_RULES_TEXT: &str = "(IFNULL(strip_reminder_text(cards.oracle_text), '') != '' OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND IFNULL(strip_reminder_text(card_faces.oracle_text), '') != ''))";
+
+// Modal spells say how many modes to choose followed by an em dash and a list
+// of the modes.
+const MODAL_PATTERN: &str =
+    r"\bchoose (one|two|three|four|one or more|one or both|any number)\b[^.]*—";
+
+impl ToSql for IsPredicate {
+    fn to_sql(&self) -> SQL {
+        match self {
+            IsPredicate::Split => layout_in(&["split"]),
+            IsPredicate::Flip => layout_in(&["flip"]),
+            IsPredicate::Transform => layout_in(&["transform"]),
+            IsPredicate::Meld => layout_in(&["meld"]),
+            IsPredicate::Leveler => layout_in(&["leveler"]),
+            IsPredicate::Dfc => {
+                layout_in(&["transform", "modal_dfc", "meld", "double_faced_token"])
+            }
+            IsPredicate::Mdfc => layout_in(&["modal_dfc"]),
+            // A spell is anything that's cast, so anything but a land. Only the
+            // front face is checked so that cards like modal double faced
+            // cards with a land on the back are still spells.
+            IsPredicate::Spell => {
+                let _where = format!(
+                    "({types} AND (CASE WHEN INSTR(cards.type_line, ' // ') > 0 THEN SUBSTR(cards.type_line, 1, INSTR(cards.type_line, ' // ') - 1) ELSE cards.type_line END) NOT LIKE '%Land%')",
+                    types = type_line_has_any(&[
+                        "Artifact",
+                        "Battle",
+                        "Creature",
+                        "Enchantment",
+                        "Instant",
+                        "Kindred",
+                        "Planeswalker",
+                        "Sorcery",
+                        "Tribal",
+                    ]),
+                );
+                SQL::new(_where, vec![])
+            }
+            IsPredicate::Permanent => SQL::new(
+                type_line_has_any(&[
+                    "Artifact",
+                    "Battle",
+                    "Creature",
+                    "Enchantment",
+                    "Land",
+                    "Planeswalker",
+                ]),
+                vec![],
+            ),
+            IsPredicate::Historic => SQL::new(
+                type_line_has_any(&["Artifact", "Legendary", "Saga"]),
+                vec![],
+            ),
+            IsPredicate::Vanilla => {
+                let _where = format!(
+                    "({creature} AND NOT {HAS_RULES_TEXT})",
+                    creature = type_line_has_any(&["Creature"]),
+                );
+                SQL::new(_where, vec![])
+            }
+            // French vanilla creatures have nothing but keyword abilities, so
+            // their rules text is checked against the keywords stored for them.
+            IsPredicate::FrenchVanilla => {
+                let _where = format!(
+                    "({creature} AND only_keywords(cards.oracle_text, (SELECT GROUP_CONCAT(card_keywords.keyword, '|') FROM card_keywords WHERE card_keywords.card_id = cards.id)))",
+                    creature = type_line_has_any(&["Creature"]),
+                );
+                SQL::new(_where, vec![])
+            }
+            IsPredicate::Bear => {
+                let _where = format!(
+                    "({creature} AND cards.cmc = 2 AND cards.power = '2' AND cards.toughness = '2')",
+                    creature = type_line_has_any(&["Creature"]),
+                );
+                SQL::new(_where, vec![])
+            }
+            IsPredicate::Modal => card_or_faces(|table| {
+                let _where = format!("{table}.oracle_text REGEXP ?", table = table);
+                SQL::new(_where, vec![]).with_params(vec![Value::Text(MODAL_PATTERN.to_string())])
+            }),
+            IsPredicate::Reprint => SQL::new("(cards.reprint = TRUE)".to_string(), vec![]),
+            // Unique cards have only been in a single set.
+            IsPredicate::Unique => {
+                let _where = format!("({sets} = 1)", sets = PrintCount::Sets.as_sql());
+                SQL::new(_where, vec![])
+            }
+            IsPredicate::Digital => SQL::new("(cards.digital = TRUE)".to_string(), vec![]),
+            IsPredicate::Foil => has_finish("foil"),
+            IsPredicate::Nonfoil => has_finish("nonfoil"),
+            IsPredicate::Etched => has_finish("etched"),
+            IsPredicate::Glossy => has_finish("glossy"),
+            IsPredicate::FullArt => SQL::new("(cards.full_art = TRUE)".to_string(), vec![]),
+            IsPredicate::Hires => SQL::new("(cards.highres_image = TRUE)".to_string(), vec![]),
+            IsPredicate::ManaSymbol(kind) => kind.to_sql(),
+        }
+    }
+}
+
+impl ToSql for ShortcutQuery {
+    fn to_sql(&self) -> SQL {
+        let sql = self.search.to_sql();
+        let _where = if self.negated {
+            format!("NOT ({clauses})", clauses = sql.where_clauses)
+        } else {
+            format!("({clauses})", clauses = sql.where_clauses)
+        };
+        SQL::new(_where, sql.join_clauses).with_params(sql.params)
+    }
+}
+
+impl ToSql for IsQuery {
+    fn to_sql(&self) -> SQL {
+        let sql = self.predicate.to_sql();
+        if self.negated {
+            let _where = format!("NOT {clauses}", clauses = sql.where_clauses);
+            SQL::new(_where, sql.join_clauses).with_params(sql.params)
+        } else {
+            sql
+        }
+    }
+}
+
+impl ToSql for TypeLineQuery {
+    fn to_sql(&self) -> SQL {
+        if self.operand.is_empty() {
+            SQL::default()
+        } else {
+            // Each face has its own type line, which a search like
+            // t:/^land — / needs to see on its own.
+            card_or_faces(|table| {
+                text_operand_sql(&format!("{table}.type_line", table = table), &self.operand)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        database::{functions::add_functions, Database},
+        search::{self},
+    };
+
+    fn where_and_params(input: &str) -> (String, Vec<Value>) {
+        let sql = search::search(input).unwrap().to_sql();
+        (sql.where_clauses, sql.params)
+    }
+
+    // #[test]
+    // pub fn equals_esper() {
+    //     let search = search::search("c=ESPER").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected =
+    //         "(cards.B=TRUE AND cards.G=FALSE AND cards.R=FALSE AND cards.U=TRUE AND cards.W=TRUE)";
+    //     assert_eq!(actual, expected)
+    // }
+
+    // #[test]
+    // pub fn not_equals_esper() {
+    //     let search = search::search("c!=ESPER").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "(cards.B=FALSE AND cards.U=FALSE AND cards.W=FALSE)";
+    //     assert_eq!(actual, expected)
+    // }
+
+    // #[test]
+    // pub fn not_esper_and_not_golgari() {
+    //     let search = search::search("c!=ESPER c!=GOLGARI").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "(cards.B=FALSE AND cards.U=FALSE AND cards.W=FALSE) AND (cards.B=FALSE AND cards.G=FALSE)";
+    //     assert_eq!(actual, expected);
+    // }
+
+    // #[test]
+    // pub fn other_greater_or_equal_esper() {
+    //     let search = search::search("c>=ESPER").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "(cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
+    //     assert_eq!(actual, expected)
+    // }
+
+    // #[test]
+    // pub fn less_than_or_equal_esper() {
+    //     let search = search::search("c<=ESPER").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "((cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (cards.G=FALSE AND cards.R=FALSE))";
+    //     assert_eq!(actual, expected)
+    // }
+
+    // #[test]
+    // pub fn other_less_than_or_equal_esper() {
+    //     let search = search::search("c:ESPER").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "(cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
+    //     assert_eq!(actual, expected)
+    // }
+
+    // #[test]
+    // pub fn less_than_esper() {
+    //     let search = search::search("c<ESPER").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "((cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (NOT (cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)) AND (cards.G=FALSE AND cards.R=FALSE))";
+    //     assert_eq!(actual, expected)
+    // }
+
+    // #[test]
+    // pub fn equals_esper_and_power_equals_touhgness() {
+    //     let search = search::search("c=ESPER pow=toughness").unwrap();
+    //     let actual = search.to_sql().where_clauses;
+    //     let expected = "(cards.B=TRUE AND cards.G=FALSE AND cards.R=FALSE AND cards.U=TRUE AND cards.W=TRUE) AND ((cards.power=cards.toughness))";
+    //     assert_eq!(actual, expected)
+    // }
+
+    #[test]
+    fn id_esper_less_than() {
+        let actual = where_and_params("id<esper");
+        let expected = "(cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (NOT (cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)) AND (NOT (cards.G=TRUE OR cards.R=TRUE))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+    #[test]
+    fn id_esper_less_than_or_equal() {
+        let actual = where_and_params("id<=esper");
+        let expected =
+            "(cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (cards.G=FALSE AND cards.R=FALSE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+    #[test]
+    fn id_esper_not_equal() {
+        let actual = where_and_params("id!=esper");
+        let expected = "NOT (cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+    #[test]
+    fn id_esper_colon() {
+        let actual = where_and_params("id:esper");
+        let expected =
+            "(cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (cards.G=FALSE AND cards.R=FALSE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+    #[test]
+    fn id_esper_equal() {
+        let actual = where_and_params("id=esper");
+        let expected =
+            "cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE AND NOT (cards.G=TRUE OR cards.R=TRUE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+    #[test]
+    fn id_esper_greater_than() {
+        let actual = where_and_params("id>esper");
+        let expected =
+            "(cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE) AND (cards.G=TRUE OR cards.R=TRUE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+    #[test]
+    fn id_esper_greater_than_or_equal() {
+        let actual = where_and_params("id>=esper");
+        let expected = "cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_less_than() {
+        let actual = where_and_params("id<wubrg");
+        let expected = "(cards.B=TRUE OR cards.G=TRUE OR cards.R=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (NOT (cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_less_than_or_equal() {
+        let actual = where_and_params("id<=wubrg");
+        let expected =
+            "cards.B=TRUE OR cards.G=TRUE OR cards.R=TRUE OR cards.U=TRUE OR cards.W=TRUE";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_not_equal() {
+        let actual = where_and_params("id!=wubrg");
+        let expected = "NOT (cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_colon() {
+        let actual = where_and_params("id:wubrg");
+        let expected =
+            "cards.B=TRUE OR cards.G=TRUE OR cards.R=TRUE OR cards.U=TRUE OR cards.W=TRUE";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_equals() {
+        let actual = where_and_params("id=wubrg");
+        let expected =
+            "cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_greater_than() {
+        let actual = where_and_params("id>wubrg");
+        // This isn't really right, but it'll do for now. I kinda doubt magic
+        // will add more colors.
+        let expected =
+            "cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_wubrg_greater_than_or_equal() {
+        let actual = where_and_params("id>=wubrg");
+        // This isn't really right, but it'll do for now. I kinda doubt magic
+        // will add more colors.
+        let expected =
+            "cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_rakdos_equal() {
+        let actual = where_and_params("id=rakdos");
+        let expected =
+            "cards.B=TRUE AND cards.R=TRUE AND NOT (cards.G=TRUE OR cards.U=TRUE OR cards.W=TRUE)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn id_rakdos_less_than() {
+        let actual = where_and_params("id<rakdos");
+        let expected = "(cards.B=TRUE OR cards.R=TRUE) AND (NOT (cards.B=TRUE AND cards.R=TRUE)) AND (NOT (cards.G=TRUE OR cards.U=TRUE OR cards.W=TRUE))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn name_with_apostrophe_is_a_param() {
+        let actual = where_and_params(r#""Urza's Saga""#);
+        let expected = (
+            "(((cards.name LIKE ? ESCAPE '\\')) OR ((cards.printed_name IS NOT NULL AND (cards.printed_name LIKE ? ESCAPE '\\'))))".to_string(),
+            vec![
+                Value::Text("%Urza's Saga%".to_string()),
+                Value::Text("%Urza's Saga%".to_string()),
+            ],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn like_wildcards_in_search_text_are_escaped() {
+        let actual = where_and_params(r#""100%_""#);
+        assert_eq!(actual.1[0], Value::Text(r"%100\%\_%".to_string()));
+    }
+
+    #[test]
+    fn like_wildcards_match_themselves() {
+        let fixture = "INSERT INTO cards (id, name, object) VALUES
+            ('underscores', '_____', 'card'),
+            ('percent', '100% Pure', 'card'),
+            ('bears', 'Grizzly Bears', 'card');";
+        assert_eq!(matching_names(fixture, r#""_""#), vec!["_____"]);
+        assert_eq!(matching_names(fixture, r#""%""#), vec!["100% Pure"]);
+    }
+
+    #[test]
+    fn oracle_text_is_a_param() {
+        let actual = where_and_params(r#"o:"'; DROP TABLE cards; --""#);
+        let expected = (
+            "((((strip_reminder_text(cards.oracle_text) LIKE ? ESCAPE '\\')) OR ((cards.printed_text IS NOT NULL AND (strip_reminder_text(cards.printed_text) LIKE ? ESCAPE '\\')))) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (((strip_reminder_text(card_faces.oracle_text) LIKE ? ESCAPE '\\')) OR ((card_faces.printed_text IS NOT NULL AND (strip_reminder_text(card_faces.printed_text) LIKE ? ESCAPE '\\'))))))".to_string(),
+            vec![
+                Value::Text("%'; DROP TABLE cards; --%".to_string()),
+                Value::Text("%'; DROP TABLE cards; --%".to_string()),
+                Value::Text("%'; DROP TABLE cards; --%".to_string()),
+                Value::Text("%'; DROP TABLE cards; --%".to_string()),
+            ],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn type_line_is_a_param() {
+        let actual = where_and_params("t:creature");
+        let expected = (
+            "((cards.type_line LIKE ? ESCAPE '\\') OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (card_faces.type_line LIKE ? ESCAPE '\\')))".to_string(),
+            vec![
+                Value::Text("%creature%".to_string()),
+                Value::Text("%creature%".to_string()),
+            ],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn exact_name_is_a_param() {
+        let actual = where_and_params("!fire");
+        let expected = (
+            "((cards.name = ? COLLATE NOCASE OR (cards.printed_name IS NOT NULL AND cards.printed_name = ? COLLATE NOCASE)) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (card_faces.name = ? COLLATE NOCASE OR (card_faces.printed_name IS NOT NULL AND card_faces.printed_name = ? COLLATE NOCASE))))".to_string(),
+            vec![
+                Value::Text("fire".to_string()),
+                Value::Text("fire".to_string()),
+                Value::Text("fire".to_string()),
+                Value::Text("fire".to_string()),
+            ],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    // An in-memory database with the app's tables and functions, populated by
+    // `fixture`.
+    fn test_database(fixture: &str) -> rusqlite::Connection {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        add_functions(&conn).unwrap();
+        conn.execute_batch(include_str!("../db/create_tables.sql"))
+            .unwrap();
+        conn.execute_batch(fixture).unwrap();
+        conn
+    }
+
+    fn matching_column(fixture: &str, column: &str, sql: SQL) -> Vec<String> {
+        let conn = test_database(fixture);
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT {column} FROM cards {} {} ORDER BY {column}",
+                sql.joins(),
+                sql.wheres()
+            ))
+            .unwrap();
+        stmt.query_map(rusqlite::params_from_iter(sql.params.iter()), |row| {
+            row.get(0)
+        })
+        .unwrap()
+        .collect::<Result<Vec<String>, _>>()
+        .unwrap()
+    }
+
+    // Runs the query against an in-memory database populated by `fixture` and
+    // returns the names of the matching cards.
+    fn matching_names(fixture: &str, query: &str) -> Vec<String> {
+        matching_column(
+            fixture,
+            "cards.name",
+            search::search(query).unwrap().to_sql(),
+        )
+    }
+
+    // Like `matching_names`, but applies the defaults the app adds to every
+    // search and returns ids so different prints can be told apart.
+    fn matching_ids(fixture: &str, query: &str) -> Vec<String> {
+        matching_column(fixture, "cards.id", Search::from(query).to_sql())
+    }
+
+    fn exact_name_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object) VALUES ('fire-ice', 'Fire // Ice', 'card'), ('fireball', 'Fireball', 'card');
+             INSERT INTO card_faces (face_index, card_id, name) VALUES (0, 'fire-ice', 'Fire'), (1, 'fire-ice', 'Ice');",
+            query,
+        )
+    }
+
+    #[test]
+    fn exact_name_matches_face_names_case_insensitively() {
+        assert_eq!(exact_name_matches("!fire"), vec!["Fire // Ice"]);
+        assert_eq!(exact_name_matches("!ICE"), vec!["Fire // Ice"]);
+        assert_eq!(exact_name_matches("!fireball"), vec!["Fireball"]);
+        assert_eq!(exact_name_matches(r#"!"fire // ice""#), vec!["Fire // Ice"]);
+        assert!(exact_name_matches("!fir").is_empty());
+    }
+
+    #[test]
+    fn mana_cost_is_normalized_into_a_param() {
+        let actual = where_and_params("m>3wu");
+        let expected = (
+            "(mana_cost_compare(cards.mana_cost, ?, '>') OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND mana_cost_compare(card_faces.mana_cost, ?, '>')))".to_string(),
+            vec![
+                Value::Text("{3}{W}{U}".to_string()),
+                Value::Text("{3}{W}{U}".to_string()),
+            ],
+        );
+        assert_eq!(actual, expected);
+    }
+
+    fn mana_cost_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, mana_cost) VALUES
+                ('ajani', 'Ajani''s Pridemate', 'card', '{1}{W}'),
+                ('spellskite', 'Spellskite', 'card', '{2}'),
+                ('mutagenic', 'Mutagenic Growth', 'card', '{G/P}'),
+                ('reaper', 'Reaper King', 'card', '{2/W}{2/U}{2/B}{2/R}{2/G}'),
+                ('delver', 'Delver of Secrets // Insectile Aberration', 'card', NULL);
+             INSERT INTO card_faces (face_index, card_id, name, mana_cost) VALUES
+                (0, 'delver', 'Delver of Secrets', '{U}'),
+                (1, 'delver', 'Insectile Aberration', '');",
+            query,
+        )
+    }
+
+    #[test]
+    fn mana_cost_compares_as_a_multiset() {
+        assert_eq!(mana_cost_matches("m:w"), vec!["Ajani's Pridemate"]);
+        assert_eq!(mana_cost_matches("m=1w"), vec!["Ajani's Pridemate"]);
+        assert_eq!(
+            mana_cost_matches("m<3w"),
+            vec!["Ajani's Pridemate", "Spellskite"]
+        );
+        assert_eq!(
+            mana_cost_matches("m>{1}"),
+            vec!["Ajani's Pridemate", "Spellskite"]
+        );
+    }
+
+    #[test]
+    fn mana_cost_checks_faces() {
+        assert_eq!(
+            mana_cost_matches("m={u}"),
+            vec!["Delver of Secrets // Insectile Aberration"]
+        );
+    }
+
+    #[test]
+    fn hybrid_and_phyrexian_symbols() {
+        assert_eq!(mana_cost_matches("is:hybrid"), vec!["Reaper King"]);
+        assert_eq!(mana_cost_matches("is:phyrexian"), vec!["Mutagenic Growth"]);
+    }
+
+    fn layout_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, layout) VALUES
+                ('fire-ice', 'Fire // Ice', 'card', 'split'),
+                ('delver', 'Delver of Secrets // Insectile Aberration', 'card', 'transform'),
+                ('valki', 'Valki, God of Lies // Tibalt, Cosmic Impostor', 'card', 'modal_dfc'),
+                ('bruna', 'Bruna, the Fading Light // Brisela, Voice of Nightmares', 'card', 'meld'),
+                ('kiki', 'Budoka Gardener // Dokai, Weaver of Life', 'card', 'flip'),
+                ('student', 'Student of Warfare', 'card', 'leveler'),
+                ('bolt', 'Lightning Bolt', 'card', 'normal');",
+            query,
+        )
+    }
+
+    #[test]
+    fn layout_predicates() {
+        assert_eq!(layout_matches("is:split"), vec!["Fire // Ice"]);
+        assert_eq!(
+            layout_matches("is:flip"),
+            vec!["Budoka Gardener // Dokai, Weaver of Life"]
+        );
+        assert_eq!(layout_matches("is:leveler"), vec!["Student of Warfare"]);
+        assert_eq!(
+            layout_matches("is:mdfc"),
+            vec!["Valki, God of Lies // Tibalt, Cosmic Impostor"]
+        );
+        assert_eq!(
+            layout_matches("is:dfc"),
+            vec![
+                "Bruna, the Fading Light // Brisela, Voice of Nightmares",
+                "Delver of Secrets // Insectile Aberration",
+                "Valki, God of Lies // Tibalt, Cosmic Impostor",
+            ]
+        );
+    }
+
+    #[test]
+    fn is_predicates_can_be_negated() {
+        let not_dfc = vec![
+            "Budoka Gardener // Dokai, Weaver of Life",
+            "Fire // Ice",
+            "Lightning Bolt",
+            "Student of Warfare",
+        ];
+        assert_eq!(layout_matches("-is:dfc"), not_dfc);
+        assert_eq!(layout_matches("not:dfc"), not_dfc);
+        assert_eq!(layout_matches("-not:meld"), layout_matches("is:meld"));
+    }
+
+    fn card_role_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, type_line, oracle_text, cmc, power, toughness) VALUES
+                ('bonecrusher', 'Bonecrusher Giant // Stomp', 'card', 'Creature — Giant // Instant — Adventure', NULL, 3, '4', '3'),
+                ('benalia', 'History of Benalia', 'card', 'Legendary Enchantment — Saga', '(As this Saga enters and after your draw step, add a lore counter.)\nI, II — Create a 2/2 white Knight creature token with vigilance.\nIII — Knights you control get +2/+1 until end of turn.', 3, NULL, NULL),
+                ('eldest', 'The Eldest Reborn', 'card', 'Enchantment — Saga', '(As this Saga enters and after your draw step, add a lore counter.)\nI — Each opponent sacrifices a creature or planeswalker.', 5, NULL, NULL),
+                ('urza', 'Urza''s Ruinous Blast', 'card', 'Legendary Sorcery', '(You may cast a legendary sorcery only if you control a legendary creature or planeswalker.)\nExile all nonland permanents that aren''t legendary.', 5, NULL, NULL),
+                ('dryad', 'Dryad Arbor', 'card', 'Land Creature — Forest Dryad', '(Dryad Arbor isn''t a spell, it''s affected by summoning sickness, and it has \"{T}: Add {G}.\")', 0, '1', '1'),
+                ('grizzly', 'Grizzly Bears', 'card', 'Creature — Bear', NULL, 2, '2', '2'),
+                ('serra', 'Serra Angel', 'card', 'Creature — Angel', 'Flying\nVigilance', 5, '4', '4'),
+                ('cryptic', 'Cryptic Command', 'card', 'Instant', 'Choose two —\n• Counter target spell.\n• Return target permanent to its owner''s hand.', 4, NULL, NULL),
+                ('seat', 'Seat of the Synod', 'card', 'Artifact Land', '{T}: Add {U}.', 0, NULL, NULL);
+             INSERT INTO card_faces (face_index, card_id, name, type_line, oracle_text) VALUES
+                (0, 'bonecrusher', 'Bonecrusher Giant', 'Creature — Giant', 'Whenever Bonecrusher Giant becomes the target of a spell, Bonecrusher Giant deals 2 damage to that spell''s controller.'),
+                (1, 'bonecrusher', 'Stomp', 'Instant — Adventure', 'Damage can''t be prevented this turn. Stomp deals 2 damage to any target.');
+             INSERT INTO card_keywords (card_id, keyword) VALUES
+                ('serra', 'Flying'), ('serra', 'Vigilance');",
+            query,
+        )
+    }
+
+    #[test]
+    fn spell_and_permanent_predicates() {
+        assert_eq!(
+            card_role_matches("is:spell"),
+            vec![
+                "Bonecrusher Giant // Stomp",
+                "Cryptic Command",
+                "Grizzly Bears",
+                "History of Benalia",
+                "Serra Angel",
+                "The Eldest Reborn",
+                "Urza's Ruinous Blast",
+            ]
+        );
+        assert_eq!(
+            card_role_matches("is:permanent"),
+            vec![
+                "Bonecrusher Giant // Stomp",
+                "Dryad Arbor",
+                "Grizzly Bears",
+                "History of Benalia",
+                "Seat of the Synod",
+                "Serra Angel",
+                "The Eldest Reborn",
+            ]
+        );
+    }
+
+    #[test]
+    fn historic_predicate() {
+        assert_eq!(
+            card_role_matches("is:historic"),
+            vec![
+                "History of Benalia",
+                "Seat of the Synod",
+                "The Eldest Reborn",
+                "Urza's Ruinous Blast",
+            ]
+        );
+    }
+
+    #[test]
+    fn vanilla_predicates_ignore_reminder_text() {
+        assert_eq!(
+            card_role_matches("is:vanilla"),
+            vec!["Dryad Arbor", "Grizzly Bears"]
+        );
+        assert_eq!(card_role_matches("is:frenchvanilla"), vec!["Serra Angel"]);
+        assert_eq!(card_role_matches("is:bear"), vec!["Grizzly Bears"]);
+    }
+
+    #[test]
+    fn modal_predicate() {
+        assert_eq!(card_role_matches("is:modal"), vec!["Cryptic Command"]);
+    }
+
+    fn release_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, set_code, released_at) VALUES
+                ('lea-bolt', 'Lightning Bolt', 'card', 'lea', '1993-08-05'),
+                ('ori-jace', 'Jace, Vryn''s Prodigy', 'card', 'ori', '2015-07-17'),
+                ('bfz-gideon', 'Gideon, Ally of Zendikar', 'card', 'bfz', '2015-10-02'),
+                ('war-liliana', 'Liliana, Dreadhorde General', 'card', 'war', '2019-05-03');",
+            query,
+        )
+    }
+
+    #[test]
+    fn year_compares_release_year() {
+        assert_eq!(
+            release_matches("year=2015"),
+            vec!["Gideon, Ally of Zendikar", "Jace, Vryn's Prodigy"]
+        );
+        assert_eq!(release_matches("year<=1994"), vec!["Lightning Bolt"]);
+    }
+
+    #[test]
+    fn date_compares_against_date_or_set() {
+        assert_eq!(
+            release_matches("date>=2015-08-18"),
+            vec!["Gideon, Ally of Zendikar", "Liliana, Dreadhorde General"]
+        );
+        assert_eq!(
+            release_matches("date>ori"),
+            vec!["Gideon, Ally of Zendikar", "Liliana, Dreadhorde General"]
+        );
+        assert_eq!(release_matches("date=ori"), vec!["Jace, Vryn's Prodigy"]);
+    }
+
+    fn price_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, oracle_id, set_code) VALUES
+                ('lea-bolt', 'Lightning Bolt', 'card', 'bolt', 'lea'),
+                ('m10-bolt', 'Lightning Bolt', 'card', 'bolt', 'm10'),
+                ('2x2-bolt', 'Lightning Bolt', 'card', 'bolt', '2x2'),
+                ('ema-swords', 'Swords to Plowshares', 'card', 'swords', 'ema'),
+                ('sld-swords', 'Swords to Plowshares', 'card', 'swords', 'sld');
+             INSERT INTO card_prices (card_id, usd, usd_foil, usd_etched, eur, eur_foil, tix) VALUES
+                ('lea-bolt', 450.0, NULL, NULL, 380.0, NULL, NULL),
+                ('m10-bolt', 1.25, 6.0, NULL, 1.1, NULL, 0.05),
+                ('2x2-bolt', NULL, 2.5, 0.75, NULL, 2.0, 0.02),
+                ('ema-swords', 0.5, 3.0, NULL, 0.4, NULL, 1.5),
+                ('sld-swords', NULL, 12.0, NULL, NULL, NULL, NULL);",
+            query,
+        )
+    }
+
+    #[test]
+    fn price_compares_nonfoil_then_foil_prices() {
+        assert_eq!(price_matches("usd>100"), vec!["Lightning Bolt"]);
+        assert_eq!(price_matches("usd>=10"), price_matches("usd>100 or usd=12"));
+        assert_eq!(
+            price_matches("usd<=3 set:2x2"),
+            vec!["Lightning Bolt"],
+            "Prints without a nonfoil price use their foil price."
+        );
+        assert_eq!(price_matches("tix>1"), vec!["Swords to Plowshares"]);
+        assert!(price_matches("eur<0.1").is_empty());
+    }
+
+    #[test]
+    fn cheapest_picks_one_print_per_card() {
+        let fixture = "INSERT INTO cards (id, name, object, oracle_id, lang) VALUES
+                ('lea-bolt', 'Lightning Bolt', 'card', 'bolt', 'en'),
+                ('2x2-bolt', 'Lightning Bolt', 'card', 'bolt', 'en'),
+                ('ema-swords', 'Swords to Plowshares', 'card', 'swords', 'en'),
+                ('sld-swords', 'Swords to Plowshares', 'card', 'swords', 'en'),
+                ('sld-bolt', 'Lightning Bolt // Lightning Bolt', 'card', NULL, 'en');
+             INSERT INTO card_faces (face_index, card_id, name, oracle_id) VALUES
+                (0, 'sld-bolt', 'Lightning Bolt', 'bolt'),
+                (1, 'sld-bolt', 'Lightning Bolt', 'bolt');
+             INSERT INTO card_prices (card_id, usd, usd_foil, tix) VALUES
+                ('lea-bolt', 450.0, NULL, 0.01),
+                ('2x2-bolt', NULL, 2.5, 0.02),
+                ('ema-swords', 0.5, 3.0, NULL),
+                ('sld-swords', NULL, 12.0, NULL),
+                ('sld-bolt', 1.0, NULL, NULL);";
+        assert_eq!(
+            matching_ids(fixture, "cheapest:usd"),
+            vec!["ema-swords", "sld-bolt"]
+        );
+        assert_eq!(matching_ids(fixture, "cheapest:tix"), vec!["lea-bolt"]);
+    }
+
+    fn language_matches(query: &str) -> Vec<String> {
+        matching_ids(
+            "INSERT INTO cards (id, name, object, oracle_id, lang, printed_name, oracle_text, printed_text) VALUES
+                ('en-bolt', 'Lightning Bolt', 'card', 'bolt', 'en', NULL, 'Lightning Bolt deals 3 damage to any target.', NULL),
+                ('ja-bolt', 'Lightning Bolt', 'card', 'bolt', 'ja', '稲妻', 'Lightning Bolt deals 3 damage to any target.', '稲妻は、1つを対象とし、それに3点のダメージを与える。'),
+                ('en-shock', 'Shock', 'card', 'shock', 'en', NULL, 'Shock deals 2 damage to any target.', NULL),
+                ('ru-giant', 'Hill Giant', 'card', 'giant', 'ru', 'Горный Великан', NULL, NULL);",
+            query,
+        )
+    }
+
+    #[test]
+    fn searches_default_to_english() {
+        assert_eq!(language_matches("bolt"), vec!["en-bolt"]);
+        assert_eq!(language_matches("giant"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn english_default_applies_to_every_branch() {
+        assert_eq!(language_matches("bolt or giant"), vec!["en-bolt"]);
+        assert_eq!(language_matches("giant or bolt"), vec!["en-bolt"]);
+        assert_eq!(
+            language_matches("giant (giant or bolt) lang:any"),
+            vec!["ru-giant"]
+        );
+    }
+
+    #[test]
+    fn lang_chooses_the_language() {
+        assert_eq!(language_matches("bolt lang:ja"), vec!["ja-bolt"]);
+        assert_eq!(
+            language_matches("bolt lang:any"),
+            vec!["en-bolt", "ja-bolt"]
+        );
+        assert_eq!(language_matches("-lang:en"), vec!["ja-bolt", "ru-giant"]);
+    }
+
+    #[test]
+    fn lang_any_doesnt_filter_anything() {
+        assert_eq!(
+            language_matches("bolt or lang:any"),
+            vec!["en-bolt", "ja-bolt"]
+        );
+        assert_eq!(
+            language_matches("bolt -lang:any"),
+            vec!["en-bolt", "ja-bolt"]
+        );
+    }
+
+    #[test]
+    fn in_finds_cards_ever_printed_in_a_language() {
+        assert_eq!(language_matches("in:ja"), vec!["en-bolt"]);
+        assert_eq!(language_matches("in:ru lang:any"), vec!["ru-giant"]);
+    }
+
+    #[test]
+    fn in_finds_reversible_prints_by_their_face_oracle_id() {
+        let fixture = "INSERT INTO cards (id, name, object, oracle_id, lang) VALUES
+                ('en-bolt', 'Lightning Bolt', 'card', 'bolt', 'en'),
+                ('sld-bolt', 'Lightning Bolt // Lightning Bolt', 'card', NULL, 'en'),
+                ('ja-sld-bolt', 'Lightning Bolt // Lightning Bolt', 'card', NULL, 'ja');
+             INSERT INTO card_faces (face_index, card_id, name, oracle_id) VALUES
+                (0, 'sld-bolt', 'Lightning Bolt', 'bolt'),
+                (1, 'sld-bolt', 'Lightning Bolt', 'bolt'),
+                (0, 'ja-sld-bolt', 'Lightning Bolt', 'bolt'),
+                (1, 'ja-sld-bolt', 'Lightning Bolt', 'bolt');";
+        assert_eq!(matching_ids(fixture, "in:ja"), vec!["en-bolt", "sld-bolt"]);
+    }
+
+    #[test]
+    fn names_and_text_match_what_is_printed() {
+        assert_eq!(language_matches("稲妻 lang:any"), vec!["ja-bolt"]);
+        assert_eq!(
+            language_matches("!\"Горный Великан\" lang:ru"),
+            vec!["ru-giant"]
+        );
+        assert_eq!(language_matches("o:ダメージ lang:ja"), vec!["ja-bolt"]);
+    }
+
+    fn extras_matches(query: &str) -> Vec<String> {
+        matching_ids(
+            "INSERT INTO cards (id, name, object, lang, layout, set_code, set_type, type_line) VALUES
+                ('bolt', 'Lightning Bolt', 'card', 'en', 'normal', 'm10', 'core', 'Instant'),
+                ('goblin', 'Goblin', 'card', 'en', 'token', 'tm10', 'token', 'Token Creature — Goblin'),
+                ('bolt-art', 'Lightning Bolt', 'card', 'en', 'art_series', 'astx', 'memorabilia', 'Card'),
+                ('scheme', 'All in Good Time', 'card', 'en', 'scheme', 'oarc', 'archenemy', 'Scheme'),
+                ('plane', 'Academy at Tolaria West', 'card', 'en', 'planar', 'ohop', 'planechase', 'Plane — Dominaria'),
+                ('ajani', 'Ajani Goldmane', 'card', 'en', 'normal', 'lrw', 'expansion', 'Legendary Planeswalker — Ajani');",
+            query,
+        )
+    }
+
+    #[test]
+    fn extras_are_hidden_by_default() {
+        assert_eq!(extras_matches(""), vec!["ajani", "bolt"]);
+        assert_eq!(extras_matches("bolt"), vec!["bolt"]);
+        assert_eq!(extras_matches("t:planeswalker"), vec!["ajani"]);
+        assert_eq!(extras_matches("-t:token"), vec!["ajani", "bolt"]);
+    }
+
+    #[test]
+    fn extras_are_shown_when_asked_for() {
+        assert_eq!(
+            extras_matches("bolt include:extras"),
+            vec!["bolt", "bolt-art"]
+        );
+        assert_eq!(extras_matches("t:token"), vec!["goblin"]);
+        assert_eq!(extras_matches("t:plane"), vec!["ajani", "plane"]);
+        assert_eq!(extras_matches("e:astx"), vec!["bolt-art"]);
+        assert_eq!(extras_matches("st:archenemy"), vec!["scheme"]);
+    }
+
+    #[test]
+    fn include_doesnt_filter_anything_itself() {
+        assert_eq!(extras_matches("t:token or include:extras"), vec!["goblin"]);
+        assert_eq!(extras_matches("-include:extras"), vec!["ajani", "bolt"]);
+        assert_eq!(extras_matches("bolt -include:extras"), vec!["bolt"]);
+    }
+
+    fn goblin_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, cmc, type_line) VALUES
+                ('guide', 'Goblin Guide', 'card', 1, 'Creature — Goblin'),
+                ('bolt', 'Lightning Bolt', 'card', 1, 'Instant');",
+            query,
+        )
+    }
+
+    #[test]
+    fn negated_order_doesnt_filter_anything() {
+        assert_eq!(goblin_matches("-order:cmc t:goblin"), vec!["Goblin Guide"]);
+    }
+
+    #[test]
+    fn negated_direction_doesnt_filter_anything() {
+        assert_eq!(
+            goblin_matches("t:goblin -direction:desc"),
+            vec!["Goblin Guide"]
+        );
+    }
+
+    #[test]
+    fn order_doesnt_match_everything_in_an_or() {
+        assert_eq!(
+            goblin_matches("order:cmc or t:goblin"),
+            vec!["Goblin Guide"]
+        );
+    }
+
+    // Pages through a search the same way `Cards::next_row` does, using the
+    // query the app runs, two cards at a time.
+    fn paged_ids(query: &str) -> Vec<String> {
+        let conn = test_database(
+            "INSERT INTO cards (id, name, object, oracle_id, lang, promo, illustration_id, cmc, power, rarity, released_at, edhrec_rank, set_code, collector_number, W, U, B, R, G) VALUES
+                ('a', 'Serra Angel', 'card', 'serra', 'en', 0, 'serra-art', 5, '4', 'uncommon', '1993-08-05', 900, 'lea', '39', 1, 0, 0, 0, 0),
+                ('b', 'Counterspell', 'card', 'counterspell', 'en', 0, 'counterspell-art', 2, NULL, 'uncommon', '1993-08-05', 40, 'lea', '54', 0, 1, 0, 0, 0),
+                ('c', 'Lightning Bolt', 'card', 'bolt', 'en', 0, 'bolt-alpha-art', 1, NULL, 'common', '1993-08-05', 20, 'lea', '161', 0, 0, 0, 1, 0),
+                ('d', 'Sol Ring', 'card', 'sol-ring', 'en', 0, 'sol-ring-art', 1, NULL, 'uncommon', '1993-08-05', 1, 'lea', '269', 0, 0, 0, 0, 0),
+                ('e', 'Lightning Bolt', 'card', 'bolt', 'en', 0, 'bolt-m10-art', 1, NULL, 'common', '2009-07-17', 20, 'm10', '146', 0, 0, 0, 1, 0),
+                ('f', 'Delver of Secrets', 'card', 'delver', 'en', 0, NULL, 1, NULL, 'common', '2011-09-30', NULL, 'isd', '51', 0, 1, 0, 0, 0),
+                ('g', 'Dack Fayden', 'card', 'dack', 'en', 0, 'dack-art', 3, NULL, 'mythic', '2014-05-30', 2000, 'cns', '42', 0, 0, 0, 1, 0),
+                ('h', 'Niv-Mizzet, Parun', 'card', 'niv', 'en', 0, 'niv-art', 6, '5', 'rare', '2018-10-05', 500, 'grn', '192', 0, 1, 0, 1, 0),
+                ('i', 'Lightning Bolt', 'card', 'bolt', 'en', 1, 'bolt-m10-art', 1, NULL, 'common', '2020-01-01', 20, 'pm10', '146p', 0, 0, 0, 1, 0),
+                ('j', 'Lightning Bolt', 'card', 'bolt', 'ja', 0, 'bolt-m10-art', 1, NULL, 'common', '2021-01-01', 20, 'sta', '42', 0, 0, 0, 1, 0);
+             INSERT INTO card_faces (face_index, card_id, name, power, illustration_id) VALUES
+                (0, 'f', 'Delver of Secrets', '1', 'delver-art'),
+                (1, 'f', 'Insectile Aberration', '3', 'aberration-art');
+             INSERT INTO card_prices (card_id, usd) VALUES
+                ('a', 0.5), ('b', 1.25), ('e', 2.0), ('d', 3.0), ('g', 10.0), ('h', 0.25);",
+        );
+        page_through(&conn, query)
+    }
+
+    fn page_through(conn: &rusqlite::Connection, query: &str) -> Vec<String> {
+        let (query, params) = Database::fetch_card_ids_sql(Search::from(query));
+        let mut ids = vec![];
+        for cursor in (0..).step_by(2) {
+            let mut stmt = conn.prepare(&query).unwrap();
+            for (idx, param) in params.iter().enumerate() {
+                stmt.raw_bind_parameter(idx + 1, param).unwrap();
+            }
+            for (name, value) in [(":cursor", cursor), (":limit", 2)] {
+                let idx = stmt.parameter_index(name).unwrap().unwrap();
+                stmt.raw_bind_parameter(idx, value).unwrap();
+            }
+            let page = stmt
+                .raw_query()
+                .mapped(|row| row.get::<_, String>(0))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            if page.is_empty() {
+                break;
+            }
+            ids.extend(page);
+        }
+        ids
+    }
+
+    fn ordered_names(query: &str) -> Vec<String> {
+        let names = HashMap::from([
+            ("a", "Serra Angel"),
+            ("b", "Counterspell"),
+            ("c", "Lightning Bolt"),
+            ("d", "Sol Ring"),
+            ("e", "Lightning Bolt"),
+            ("f", "Delver of Secrets"),
+            ("g", "Dack Fayden"),
+            ("h", "Niv-Mizzet, Parun"),
+            ("i", "Lightning Bolt"),
+            ("j", "Lightning Bolt"),
+        ]);
+        paged_ids(query)
+            .iter()
+            .map(|id| names[id.as_str()].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn unique_chooses_which_prints_are_shown() {
+        assert_eq!(paged_ids("bolt"), vec!["e"]);
+        assert_eq!(paged_ids("bolt unique:cards lang:any"), vec!["e"]);
+        assert_eq!(paged_ids("bolt unique:prints"), vec!["c", "e", "i"]);
+        assert_eq!(
+            paged_ids("bolt unique:prints lang:any"),
+            vec!["c", "e", "i", "j"]
+        );
+        assert_eq!(paged_ids("bolt unique:art"), vec!["c", "e"]);
+        assert_eq!(paged_ids("delver unique:art"), vec!["f"]);
+    }
+
+    #[test]
+    fn unique_cards_groups_reversible_cards_with_their_card() {
+        let conn = test_database(
+            "INSERT INTO cards (id, name, object, oracle_id, lang, released_at) VALUES
+                ('m10-bolt', 'Lightning Bolt', 'card', 'bolt', 'en', '2009-07-17'),
+                ('sld-bolt', 'Lightning Bolt // Lightning Bolt', 'card', NULL, 'en', '2022-01-01');
+             INSERT INTO card_faces (face_index, card_id, name, oracle_id) VALUES
+                (0, 'sld-bolt', 'Lightning Bolt', 'bolt'),
+                (1, 'sld-bolt', 'Lightning Bolt', 'bolt');",
+        );
+        assert_eq!(page_through(&conn, "bolt"), vec!["sld-bolt"]);
+        assert_eq!(
+            page_through(&conn, "bolt unique:prints"),
+            vec!["m10-bolt", "sld-bolt"]
+        );
+    }
+
+    #[test]
+    fn negated_unique_doesnt_filter_anything() {
+        assert_eq!(
+            goblin_matches("-unique:prints t:goblin"),
+            vec!["Goblin Guide"]
+        );
+    }
 
-    // #[test]
-    // pub fn less_than_esper() {
-    //     let search = search::search("c<ESPER").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "((cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (NOT (cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)) AND (cards.G=FALSE AND cards.R=FALSE))";
-    //     assert_eq!(actual, expected)
-    // }
+    #[test]
+    fn results_are_sorted_by_name_by_default() {
+        assert_eq!(
+            ordered_names(""),
+            vec![
+                "Counterspell",
+                "Dack Fayden",
+                "Delver of Secrets",
+                "Lightning Bolt",
+                "Niv-Mizzet, Parun",
+                "Serra Angel",
+                "Sol Ring",
+            ]
+        );
+        assert_eq!(
+            ordered_names("direction:desc"),
+            vec![
+                "Sol Ring",
+                "Serra Angel",
+                "Niv-Mizzet, Parun",
+                "Lightning Bolt",
+                "Delver of Secrets",
+                "Dack Fayden",
+                "Counterspell",
+            ]
+        );
+    }
 
-    // #[test]
-    // pub fn equals_esper_and_power_equals_touhgness() {
-    //     let search = search::search("c=ESPER pow=toughness").unwrap();
-    //     let actual = search.to_sql().where_clauses;
-    //     let expected = "(cards.B=TRUE AND cards.G=FALSE AND cards.R=FALSE AND cards.U=TRUE AND cards.W=TRUE) AND ((cards.power=cards.toughness))";
-    //     assert_eq!(actual, expected)
-    // }
+    #[test]
+    fn results_can_be_ordered() {
+        assert_eq!(
+            ordered_names("order:cmc"),
+            vec![
+                "Delver of Secrets",
+                "Lightning Bolt",
+                "Sol Ring",
+                "Counterspell",
+                "Dack Fayden",
+                "Serra Angel",
+                "Niv-Mizzet, Parun",
+            ]
+        );
+        assert_eq!(
+            ordered_names("order:power direction:desc"),
+            vec![
+                "Niv-Mizzet, Parun",
+                "Serra Angel",
+                "Delver of Secrets",
+                "Counterspell",
+                "Dack Fayden",
+                "Lightning Bolt",
+                "Sol Ring",
+            ]
+        );
+        assert_eq!(
+            ordered_names("order:rarity direction:desc t:"),
+            vec![
+                "Dack Fayden",
+                "Niv-Mizzet, Parun",
+                "Counterspell",
+                "Serra Angel",
+                "Sol Ring",
+                "Delver of Secrets",
+                "Lightning Bolt",
+            ]
+        );
+        assert_eq!(
+            ordered_names("order:color"),
+            vec![
+                "Serra Angel",
+                "Counterspell",
+                "Delver of Secrets",
+                "Dack Fayden",
+                "Lightning Bolt",
+                "Niv-Mizzet, Parun",
+                "Sol Ring",
+            ]
+        );
+        assert_eq!(
+            ordered_names("order:edhrec"),
+            vec![
+                "Sol Ring",
+                "Lightning Bolt",
+                "Counterspell",
+                "Niv-Mizzet, Parun",
+                "Serra Angel",
+                "Dack Fayden",
+                "Delver of Secrets",
+            ]
+        );
+        assert_eq!(
+            ordered_names("order:usd direction:desc"),
+            vec![
+                "Dack Fayden",
+                "Sol Ring",
+                "Lightning Bolt",
+                "Counterspell",
+                "Serra Angel",
+                "Niv-Mizzet, Parun",
+                "Delver of Secrets",
+            ]
+        );
+        assert_eq!(
+            ordered_names("order:set"),
+            vec![
+                "Dack Fayden",
+                "Niv-Mizzet, Parun",
+                "Delver of Secrets",
+                "Serra Angel",
+                "Counterspell",
+                "Sol Ring",
+                "Lightning Bolt",
+            ]
+        );
+    }
+
+    fn print_count_matches(query: &str) -> Vec<String> {
+        matching_names(
+            concat!(
+                "INSERT INTO cards (id, name, object, oracle_id, set_code, reprint) VALUES
+                    ('lea-bolt', 'Lightning Bolt', 'card', 'bolt', 'lea', FALSE),
+                    ('m10-bolt', 'Lightning Bolt', 'card', 'bolt', 'm10', TRUE),
+                    ('m10-promo-bolt', 'Lightning Bolt', 'card', 'bolt', 'm10', TRUE),
+                    ('arn-juzam', 'Juzam Djinn', 'card', 'juzam', 'arn', FALSE),
+                    ('vma-juzam', 'Juzam Djinn', 'card', 'juzam', 'vma', TRUE),
+                    ('ktk-sage', 'Sage-Eye Harrier', 'card', 'sage', 'ktk', FALSE),
+                    ('sld-reversible-bolt', 'Lightning Bolt // Lightning Bolt', 'card', NULL, 'sld', TRUE);
+                 INSERT INTO card_faces (face_index, card_id, name, oracle_id) VALUES
+                    (0, 'sld-reversible-bolt', 'Lightning Bolt', 'bolt'),
+                    (1, 'sld-reversible-bolt', 'Lightning Bolt', 'bolt');
+                 INSERT INTO card_games (card_id, game) VALUES
+                    ('lea-bolt', 'paper'),
+                    ('m10-bolt', 'paper'),
+                    ('m10-bolt', 'mtgo'),
+                    ('m10-promo-bolt', 'paper'),
+                    ('arn-juzam', 'paper'),
+                    ('vma-juzam', 'mtgo'),
+                    ('ktk-sage', 'paper'),
+                    ('ktk-sage', 'mtgo'),
+                    ('sld-reversible-bolt', 'paper');",
+                include_str!("../db/build_oracle_prints.sql"),
+            ),
+            query,
+        )
+    }
 
     #[test]
-    fn id_esper_less_than() {
-        let actual = search::search("id<esper").unwrap().to_sql().where_clauses;
-        let expected = "(cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (NOT (cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)) AND (NOT (cards.G=TRUE OR cards.R=TRUE))";
-        assert_eq!(actual, expected);
+    fn reprint_predicates() {
+        assert_eq!(
+            print_count_matches("is:reprint"),
+            vec![
+                "Juzam Djinn",
+                "Lightning Bolt",
+                "Lightning Bolt",
+                "Lightning Bolt // Lightning Bolt",
+            ]
+        );
+        assert_eq!(
+            print_count_matches("not:reprint"),
+            vec!["Juzam Djinn", "Lightning Bolt", "Sage-Eye Harrier"]
+        );
+        assert_eq!(print_count_matches("is:unique"), vec!["Sage-Eye Harrier"]);
     }
+
     #[test]
-    fn id_esper_less_than_or_equal() {
-        let actual = search::search("id<=esper").unwrap().to_sql().where_clauses;
-        let expected =
-            "(cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (cards.G=FALSE AND cards.R=FALSE)";
-        assert_eq!(actual, expected);
+    fn print_counts_use_the_oracle_aggregate() {
+        assert_eq!(
+            print_count_matches("prints=4 set:lea"),
+            vec!["Lightning Bolt"]
+        );
+        assert_eq!(
+            print_count_matches("sets=3 set:lea"),
+            vec!["Lightning Bolt"]
+        );
+        assert_eq!(
+            print_count_matches("papersets=1"),
+            vec!["Juzam Djinn", "Juzam Djinn", "Sage-Eye Harrier"]
+        );
+        assert_eq!(
+            print_count_matches("paperprints>1 set:arn"),
+            Vec::<String>::new()
+        );
+    }
+
+    fn game_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, oracle_id, set_code, digital, arena_id, mtgo_id) VALUES
+                ('m10-bolt', 'Lightning Bolt', 'card', 'bolt', 'm10', FALSE, NULL, 31617),
+                ('sta-bolt', 'Lightning Bolt', 'card', 'bolt', 'sta', FALSE, 75180, 85880),
+                ('arn-juzam', 'Juzam Djinn', 'card', 'juzam', 'arn', FALSE, NULL, NULL),
+                ('ana-shock', 'Shock', 'card', 'shock', 'ana', TRUE, 67330, NULL),
+                ('sld-bolt', 'Lightning Bolt // Lightning Bolt', 'card', NULL, 'sld', FALSE, NULL, NULL);
+             INSERT INTO card_faces (face_index, card_id, name, oracle_id) VALUES
+                (0, 'sld-bolt', 'Lightning Bolt', 'bolt'),
+                (1, 'sld-bolt', 'Lightning Bolt', 'bolt');
+             INSERT INTO card_games (card_id, game) VALUES
+                ('m10-bolt', 'paper'), ('m10-bolt', 'mtgo'),
+                ('sta-bolt', 'paper'), ('sta-bolt', 'mtgo'), ('sta-bolt', 'arena'),
+                ('arn-juzam', 'paper'),
+                ('ana-shock', 'arena'),
+                ('sld-bolt', 'paper');",
+            query,
+        )
     }
+
     #[test]
-    fn id_esper_not_equal() {
-        let actual = search::search("id!=esper").unwrap().to_sql().where_clauses;
-        let expected = "NOT (cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
-        assert_eq!(actual, expected);
+    fn game_is_per_print_and_in_is_per_card() {
+        assert_eq!(game_matches("game:arena"), vec!["Lightning Bolt", "Shock"]);
+        assert_eq!(
+            game_matches("in:arena"),
+            vec![
+                "Lightning Bolt",
+                "Lightning Bolt",
+                "Lightning Bolt // Lightning Bolt",
+                "Shock"
+            ]
+        );
+        assert_eq!(game_matches("-in:mtgo game:paper"), vec!["Juzam Djinn"]);
     }
+
     #[test]
-    fn id_esper_colon() {
-        let actual = search::search("id:esper").unwrap().to_sql().where_clauses;
-        let expected =
-            "(cards.B=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (cards.G=FALSE AND cards.R=FALSE)";
-        assert_eq!(actual, expected);
+    fn digital_and_game_ids() {
+        assert_eq!(game_matches("is:digital"), vec!["Shock"]);
+        assert_eq!(game_matches("arenaid:75180"), vec!["Lightning Bolt"]);
+        assert_eq!(game_matches("mtgoid:31617"), vec!["Lightning Bolt"]);
+        assert!(game_matches("mtgoid:1").is_empty());
+    }
+
+    fn print_style_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, border_color, frame, full_art, highres_image, security_stamp) VALUES
+                ('4ed-serra', 'Serra Angel', 'card', 'white', '1993', FALSE, TRUE, NULL),
+                ('tsb-akroma', 'Akroma, Angel of Wrath', 'card', 'black', '1997', FALSE, TRUE, NULL),
+                ('unf-goblin', 'Goblin Blastronauts', 'card', 'borderless', '2015', TRUE, FALSE, 'acorn'),
+                ('cmr-jeska', 'Jeska, Thrice Reborn', 'card', 'black', '2015', FALSE, TRUE, 'oval');
+             INSERT INTO card_finishes (card_id, finish) VALUES
+                ('4ed-serra', 'nonfoil'),
+                ('tsb-akroma', 'foil'),
+                ('unf-goblin', 'nonfoil'), ('unf-goblin', 'foil'),
+                ('cmr-jeska', 'etched');
+             INSERT INTO card_frame_effects (card_id, frame_effect) VALUES
+                ('tsb-akroma', 'colorshifted'),
+                ('cmr-jeska', 'legendary'), ('cmr-jeska', 'etched');",
+            query,
+        )
     }
+
     #[test]
-    fn id_esper_equal() {
-        let actual = search::search("id=esper").unwrap().to_sql().where_clauses;
-        let expected =
-            "cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE AND NOT (cards.G=TRUE OR cards.R=TRUE)";
-        assert_eq!(actual, expected);
+    fn border_frame_and_stamp() {
+        assert_eq!(print_style_matches("border:white"), vec!["Serra Angel"]);
+        assert_eq!(
+            print_style_matches("frame:2015"),
+            vec!["Goblin Blastronauts", "Jeska, Thrice Reborn"]
+        );
+        assert_eq!(
+            print_style_matches("frame:colorshifted"),
+            vec!["Akroma, Angel of Wrath"]
+        );
+        assert_eq!(
+            print_style_matches("stamp:oval"),
+            vec!["Jeska, Thrice Reborn"]
+        );
     }
+
     #[test]
-    fn id_esper_greater_than() {
-        let actual = search::search("id>esper").unwrap().to_sql().where_clauses;
-        let expected =
-            "(cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE) AND (cards.G=TRUE OR cards.R=TRUE)";
-        assert_eq!(actual, expected);
+    fn finish_predicates() {
+        assert_eq!(
+            print_style_matches("is:foil"),
+            vec!["Akroma, Angel of Wrath", "Goblin Blastronauts"]
+        );
+        assert_eq!(
+            print_style_matches("is:foil is:nonfoil"),
+            vec!["Goblin Blastronauts"]
+        );
+        assert_eq!(
+            print_style_matches("is:etched"),
+            vec!["Jeska, Thrice Reborn"]
+        );
+        assert_eq!(print_style_matches("is:full"), vec!["Goblin Blastronauts"]);
+        assert_eq!(
+            print_style_matches("not:hires"),
+            vec!["Goblin Blastronauts"]
+        );
     }
+
     #[test]
-    fn id_esper_greater_than_or_equal() {
-        let actual = search::search("id>=esper").unwrap().to_sql().where_clauses;
-        let expected = "cards.B=TRUE AND cards.U=TRUE AND cards.W=TRUE";
-        assert_eq!(actual, expected);
+    fn paper_prints_are_the_ones_printed_on_paper() {
+        assert_eq!(
+            print_count_matches("paperprints=4"),
+            vec![
+                "Lightning Bolt",
+                "Lightning Bolt",
+                "Lightning Bolt",
+                "Lightning Bolt // Lightning Bolt",
+            ]
+        );
+        assert_eq!(
+            print_count_matches("paperprints=1 set:vma"),
+            vec!["Juzam Djinn"]
+        );
     }
 
     #[test]
-    fn id_wubrg_less_than() {
-        let actual = search::search("id<wubrg").unwrap().to_sql().where_clauses;
-        let expected = "(cards.B=TRUE OR cards.G=TRUE OR cards.R=TRUE OR cards.U=TRUE OR cards.W=TRUE) AND (NOT (cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE))";
-        assert_eq!(actual, expected);
+    fn oracle_text_leaves_out_reminder_text_unless_full() {
+        let fixture = "INSERT INTO cards (id, name, object, oracle_text) VALUES
+            ('rats', 'Typhoid Rats', 'card', 'Deathtouch (Any amount of damage this deals to a creature is enough to destroy it.)'),
+            ('shock', 'Shock', 'card', 'Shock deals 2 damage to any target.'),
+            ('chancery', 'Azorius Chancery', 'card', 'Azorius Chancery enters the battlefield tapped.'),
+            ('guildgate', 'Azorius Guildgate', 'card', 'Azorius Guildgate enters the battlefield tapped.'),
+            ('tundra', 'Tundra', 'card', '({T}: Add {W} or {U}.)');";
+        assert_eq!(matching_names(fixture, "o:damage"), vec!["Shock"]);
+        assert_eq!(
+            matching_names(fixture, "fo:damage"),
+            vec!["Shock", "Typhoid Rats"]
+        );
+        assert_eq!(matching_names(fixture, "fo:/^\\(/"), vec!["Tundra"]);
+        assert_eq!(matching_names(fixture, r#"o:"~ deals 2""#), vec!["Shock"]);
+        assert_eq!(
+            matching_names(fixture, r#"o:"CARDNAME enters the battlefield tapped""#),
+            vec!["Azorius Chancery", "Azorius Guildgate"]
+        );
+        assert_eq!(
+            matching_names(fixture, "o:/^~ enters/"),
+            vec!["Azorius Chancery", "Azorius Guildgate"]
+        );
+        assert_eq!(matching_names(fixture, r#"o:"Shock deals""#), vec!["Shock"]);
     }
 
     #[test]
-    fn id_wubrg_less_than_or_equal() {
-        let actual = search::search("id<=wubrg").unwrap().to_sql().where_clauses;
-        let expected =
-            "cards.B=TRUE OR cards.G=TRUE OR cards.R=TRUE OR cards.U=TRUE OR cards.W=TRUE";
+    fn shortcuts_search_for_what_they_stand_for() {
+        search::shortcut::load_built_in();
+        let fixture = "INSERT INTO cards (id, name, object, type_line, oracle_text) VALUES
+            ('hallowed', 'Hallowed Fountain', 'card', 'Land — Plains Island', '({T}: Add {W} or {U}.)\nAs Hallowed Fountain enters the battlefield, you may pay 2 life. If you don''t, it enters the battlefield tapped.'),
+            ('tundra', 'Tundra', 'card', 'Land — Plains Island', '({T}: Add {W} or {U}.)'),
+            ('flooded', 'Flooded Strand', 'card', 'Land', '{T}, Pay 1 life, Sacrifice Flooded Strand: Search your library for a Plains or Island card, put it onto the battlefield, then shuffle.'),
+            ('azorius', 'Azorius Chancery', 'card', 'Land', 'Azorius Chancery enters the battlefield tapped.\nWhen Azorius Chancery enters the battlefield, return a land you control to its owner''s hand.\n{T}: Add {W}{U}.');";
+        assert_eq!(
+            matching_names(fixture, "is:shockland"),
+            vec!["Hallowed Fountain"]
+        );
+        assert_eq!(matching_names(fixture, "is:dual"), vec!["Tundra"]);
+        assert_eq!(
+            matching_names(fixture, "is:fetchland"),
+            vec!["Flooded Strand"]
+        );
+        assert_eq!(
+            matching_names(fixture, "is:karoo"),
+            vec!["Azorius Chancery"]
+        );
+        assert_eq!(
+            matching_names(fixture, "not:karoo"),
+            vec!["Flooded Strand", "Hallowed Fountain", "Tundra"]
+        );
+    }
+
+    #[test]
+    fn reversible_cards_use_their_face_oracle_id() {
+        assert_eq!(
+            print_count_matches("prints=4 set:sld"),
+            vec!["Lightning Bolt // Lightning Bolt"]
+        );
+        assert!(print_count_matches("is:unique set:sld").is_empty());
+    }
+
+    #[test]
+    fn devotion_counts_mana_cost_symbols() {
+        let fixture = "INSERT INTO cards (id, name, object, mana_cost) VALUES
+            ('gray-merchant', 'Gray Merchant of Asphodel', 'card', '{3}{B}{B}'),
+            ('ashiok', 'Ashiok, Nightmare Weaver', 'card', '{1}{U}{B}'),
+            ('kitchen-finks', 'Kitchen Finks', 'card', '{1}{G/W}{G/W}');";
+        assert_eq!(
+            matching_names(fixture, "devotion:{u/b}{u/b}"),
+            vec!["Ashiok, Nightmare Weaver", "Gray Merchant of Asphodel"]
+        );
+        assert_eq!(
+            matching_names(fixture, "devotion>=bb"),
+            vec!["Gray Merchant of Asphodel"]
+        );
+        assert_eq!(
+            matching_names(fixture, "devotion=ww"),
+            vec!["Kitchen Finks"]
+        );
+    }
+
+    #[test]
+    fn produces_uses_color_semantics() {
+        let fixture = "INSERT INTO cards (id, name, object) VALUES
+                ('hallowed', 'Hallowed Fountain', 'card'),
+                ('plains', 'Plains', 'card'),
+                ('bolt', 'Lightning Bolt', 'card');
+             INSERT INTO card_produced_mana (card_id, produced_mana) VALUES
+                ('hallowed', 'W'), ('hallowed', 'U'), ('plains', 'W');";
+        assert_eq!(
+            matching_names(fixture, "produces:w"),
+            vec!["Hallowed Fountain", "Plains"]
+        );
+        assert_eq!(
+            matching_names(fixture, "produces=wu"),
+            vec!["Hallowed Fountain"]
+        );
+        assert_eq!(matching_names(fixture, "produces=w"), vec!["Plains"]);
+    }
+
+    fn print_text_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, artist, flavor_text, watermark) VALUES
+                ('guildmage', 'Orzhov Guildmage', 'card', 'Greg Staples', 'Mishra was here.', 'orzhov'),
+                ('bolt', 'Lightning Bolt', 'card', 'Christopher Moeller', NULL, NULL),
+                ('brutal', 'Brutal Cathar // Moonrage Brute', 'card', 'Karl Kopinski', NULL, NULL);
+             INSERT INTO card_faces (face_index, card_id, name, artist, flavor_text, watermark) VALUES
+                (0, 'brutal', 'Brutal Cathar', 'Karl Kopinski', NULL, NULL),
+                (1, 'brutal', 'Moonrage Brute', 'Chris Rahn', 'The night brings out the worst.', 'set');",
+            query,
+        )
+    }
+
+    #[test]
+    fn artist_matches_card_and_faces() {
+        assert_eq!(print_text_matches("a:staples"), vec!["Orzhov Guildmage"]);
+        assert_eq!(
+            print_text_matches(r#"artist:"chris rahn""#),
+            vec!["Brutal Cathar // Moonrage Brute"]
+        );
+    }
+
+    #[test]
+    fn artists_counts_distinct_face_artists() {
+        assert_eq!(
+            print_text_matches("artists>1"),
+            vec!["Brutal Cathar // Moonrage Brute"]
+        );
+        assert_eq!(
+            print_text_matches("artists=1"),
+            vec!["Lightning Bolt", "Orzhov Guildmage"]
+        );
+    }
+
+    #[test]
+    fn flavor_text_matches_card_and_faces() {
+        assert_eq!(print_text_matches("ft:mishra"), vec!["Orzhov Guildmage"]);
+        assert_eq!(
+            print_text_matches("flavor:/^the night/"),
+            vec!["Brutal Cathar // Moonrage Brute"]
+        );
+    }
+
+    #[test]
+    fn watermark_matches_card_and_faces() {
+        assert_eq!(print_text_matches("wm:ORZHOV"), vec!["Orzhov Guildmage"]);
+        assert_eq!(
+            print_text_matches("has:watermark"),
+            vec!["Brutal Cathar // Moonrage Brute", "Orzhov Guildmage"]
+        );
+        assert_eq!(print_text_matches("-has:watermark"), vec!["Lightning Bolt"]);
+    }
+
+    #[test]
+    fn name_regex_uses_regexp() {
+        let actual = where_and_params(r"name:/^fire\b/");
+        let expected = (
+            "(((cards.name REGEXP ?)) OR ((cards.printed_name IS NOT NULL AND (cards.printed_name REGEXP ?))))".to_string(),
+            vec![
+                Value::Text(r"^fire\b".to_string()),
+                Value::Text(r"^fire\b".to_string()),
+            ],
+        );
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn id_wubrg_not_equal() {
-        let actual = search::search("id!=wubrg").unwrap().to_sql().where_clauses;
-        let expected = "NOT (cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE)";
+    fn oracle_and_type_line_regex_use_regexp() {
+        let actual = where_and_params(r"o:/\{T\}: add/ t:/^legendary/");
+        let expected = (
+            "((((((strip_reminder_text(cards.oracle_text) REGEXP ?)) OR ((cards.printed_text IS NOT NULL AND (strip_reminder_text(cards.printed_text) REGEXP ?)))) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (((strip_reminder_text(card_faces.oracle_text) REGEXP ?)) OR ((card_faces.printed_text IS NOT NULL AND (strip_reminder_text(card_faces.printed_text) REGEXP ?))))))) AND (((cards.type_line REGEXP ?) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (card_faces.type_line REGEXP ?)))))".to_string(),
+            vec![
+                Value::Text(r"\{T\}: add".to_string()),
+                Value::Text(r"\{T\}: add".to_string()),
+                Value::Text(r"\{T\}: add".to_string()),
+                Value::Text(r"\{T\}: add".to_string()),
+                Value::Text("^legendary".to_string()),
+                Value::Text("^legendary".to_string()),
+            ],
+        );
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn id_wubrg_colon() {
-        let actual = search::search("id:wubrg").unwrap().to_sql().where_clauses;
-        let expected =
-            "cards.B=TRUE OR cards.G=TRUE OR cards.R=TRUE OR cards.U=TRUE OR cards.W=TRUE";
+    fn keyword_is_a_param() {
+        let sql = search::search("kw:flying").unwrap().to_sql();
+        assert!(sql.where_clauses.ends_with(".keyword LIKE ? ESCAPE '\\'"));
+        assert_eq!(sql.params, vec![Value::Text("%flying%".to_string())]);
+    }
+
+    #[test]
+    fn params_follow_clause_order() {
+        let actual = where_and_params("t:goblin pow>2 -sliver");
+        let expected = (
+            "((((cards.type_line LIKE ? ESCAPE '\\') OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (card_faces.type_line LIKE ? ESCAPE '\\')))) AND ((CAST(cards.power AS REAL)>2 OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND CAST(card_faces.power AS REAL)>2))) AND ( NOT ((((cards.name LIKE ? ESCAPE '\\')) OR ((cards.printed_name IS NOT NULL AND (cards.printed_name LIKE ? ESCAPE '\\')))))))"
+                .to_string(),
+            vec![
+                Value::Text("%goblin%".to_string()),
+                Value::Text("%goblin%".to_string()),
+                Value::Text("%sliver%".to_string()),
+                Value::Text("%sliver%".to_string()),
+            ],
+        );
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn id_wubrg_equals() {
-        let actual = search::search("id=wubrg").unwrap().to_sql().where_clauses;
+    fn mana_value_greater_than_or_equal() {
+        let actual = where_and_params("mv>=3");
+        let expected = "(cards.cmc>=3)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn mana_value_colon_is_equal() {
+        let actual = where_and_params("cmc:5");
+        let expected = "(cards.cmc=5)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn mana_value_even() {
+        let actual = where_and_params("mv:even");
         let expected =
-            "cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE";
-        assert_eq!(actual, expected);
+            "(cards.cmc=CAST(cards.cmc AS INTEGER) AND CAST(cards.cmc AS INTEGER) % 2 = 0)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
     }
 
     #[test]
-    fn id_wubrg_greater_than() {
-        let actual = search::search("id>wubrg").unwrap().to_sql().where_clauses;
-        // This isn't really right, but it'll do for now. I kinda doubt magic
-        // will add more colors.
+    fn mana_value_not_odd() {
+        let actual = where_and_params("manavalue!=odd");
         let expected =
-            "cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE";
+            "NOT (cards.cmc=CAST(cards.cmc AS INTEGER) AND CAST(cards.cmc AS INTEGER) % 2 = 1)";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn power_greater_than_number() {
+        let actual = where_and_params("pow>=8");
+        let expected = "(CAST(cards.power AS REAL)>=8 OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND CAST(card_faces.power AS REAL)>=8))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn power_greater_than_toughness() {
+        let actual = where_and_params("pow>tou");
+        let expected = "(CAST(cards.power AS REAL)>CAST(cards.toughness AS REAL) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND CAST(card_faces.power AS REAL)>CAST(card_faces.toughness AS REAL)))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn loyalty_colon_is_equal() {
+        let actual = where_and_params("loy:3");
+        let expected = "(CAST(cards.loyalty AS REAL)=3 OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND CAST(card_faces.loyalty AS REAL)=3))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn powtou_less_than_defense() {
+        let actual = where_and_params("pt<def");
+        let expected = "((CAST(cards.power AS REAL)+CAST(cards.toughness AS REAL))<CAST(cards.defense AS REAL) OR EXISTS (SELECT 1 FROM card_faces WHERE card_faces.card_id = cards.id AND (CAST(card_faces.power AS REAL)+CAST(card_faces.toughness AS REAL))<CAST(card_faces.defense AS REAL)))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    fn color_count_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, W, U, B, R, G, color_indicator) VALUES
+                ('bolt', 'Lightning Bolt', 'card', 0, 0, 0, 1, 0, NULL),
+                ('niv', 'Niv-Mizzet, Parun', 'card', 0, 1, 0, 1, 0, NULL),
+                ('sol', 'Sol Ring', 'card', 0, 0, 0, 0, 0, NULL),
+                ('ancestral', 'Ancestral Vision', 'card', 0, 1, 0, 0, 0, 'U'),
+                ('delver', 'Delver of Secrets // Insectile Aberration', 'card', NULL, NULL, NULL, NULL, NULL, NULL),
+                ('kenrith', 'Kenrith, the Returned King', 'card', 0, 0, 0, 1, 0, NULL);
+             INSERT INTO card_faces (face_index, card_id, name, W, U, B, R, G, color_indicator) VALUES
+                (0, 'delver', 'Delver of Secrets', 0, 1, 0, 0, 0, NULL),
+                (1, 'delver', 'Insectile Aberration', 0, 1, 0, 0, 0, 'U');
+             INSERT INTO card_color_identity (card_id, color_identity) VALUES
+                ('bolt', 'R'), ('niv', 'U'), ('niv', 'R'), ('ancestral', 'U'), ('delver', 'U'),
+                ('kenrith', 'W'), ('kenrith', 'U'), ('kenrith', 'B'), ('kenrith', 'R'), ('kenrith', 'G');",
+            query,
+        )
+    }
+
+    #[test]
+    fn colors_can_be_counted() {
+        assert_eq!(color_count_matches("c=2"), vec!["Niv-Mizzet, Parun"]);
+        assert_eq!(color_count_matches("c:0"), vec!["Sol Ring"]);
+        assert_eq!(
+            color_count_matches("color=1"),
+            vec![
+                "Ancestral Vision",
+                "Delver of Secrets // Insectile Aberration",
+                "Kenrith, the Returned King",
+                "Lightning Bolt",
+            ]
+        );
+        assert_eq!(
+            color_count_matches("id>=2"),
+            vec!["Kenrith, the Returned King", "Niv-Mizzet, Parun"]
+        );
+        assert_eq!(
+            color_count_matches("identity=5"),
+            vec!["Kenrith, the Returned King"]
+        );
+        assert_eq!(color_count_matches("id<1"), vec!["Sol Ring"]);
+    }
+
+    #[test]
+    fn has_indicator_checks_card_and_faces() {
+        assert_eq!(
+            color_count_matches("has:indicator"),
+            vec![
+                "Ancestral Vision",
+                "Delver of Secrets // Insectile Aberration"
+            ]
+        );
+    }
+
+    // Transform cards, modal double-faced cards and split cards only have
+    // their rules text and stats on their faces.
+    fn face_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, type_line, oracle_text, power, toughness) VALUES
+                ('delver', 'Delver of Secrets // Insectile Aberration', 'card', 'Creature — Human Wizard // Creature — Human Insect', NULL, NULL, NULL),
+                ('valakut', 'Valakut Awakening // Valakut Stoneforge', 'card', 'Instant // Land', NULL, NULL, NULL),
+                ('fire-ice', 'Fire // Ice', 'card', 'Instant // Instant', NULL, NULL, NULL),
+                ('bears', 'Grizzly Bears', 'card', 'Creature — Bear', NULL, '2', '2');
+             INSERT INTO card_faces (face_index, card_id, name, type_line, oracle_text, power, toughness) VALUES
+                (0, 'delver', 'Delver of Secrets', 'Creature — Human Wizard', 'At the beginning of your upkeep, look at the top card of your library. You may reveal that card. If an instant or sorcery card is revealed this way, transform Delver of Secrets.', '1', '1'),
+                (1, 'delver', 'Insectile Aberration', 'Creature — Human Insect', 'Flying', '3', '2'),
+                (0, 'valakut', 'Valakut Awakening', 'Instant', 'Put any number of cards from your hand on the bottom of your library, then draw that many cards plus one.', NULL, NULL),
+                (1, 'valakut', 'Valakut Stoneforge', 'Land', 'As Valakut Stoneforge enters the battlefield, you may pay 3 life. If you don''t, it enters the battlefield tapped.\n{T}: Add {R}.', NULL, NULL),
+                (0, 'fire-ice', 'Fire', 'Instant', 'Fire deals 2 damage divided as you choose among one or two targets.', NULL, NULL),
+                (1, 'fire-ice', 'Ice', 'Instant', 'Tap target permanent.\nDraw a card.', NULL, NULL);",
+            query,
+        )
+    }
+
+    #[test]
+    fn oracle_text_matches_faces() {
+        assert_eq!(face_matches("o:\"draw a card\""), vec!["Fire // Ice"]);
+        assert_eq!(
+            face_matches("o:draw"),
+            vec!["Fire // Ice", "Valakut Awakening // Valakut Stoneforge"]
+        );
+        assert_eq!(
+            face_matches("o:\"transform ~\""),
+            vec!["Delver of Secrets // Insectile Aberration"]
+        );
+        assert_eq!(
+            face_matches("-o:flying"),
+            vec![
+                "Fire // Ice",
+                "Grizzly Bears",
+                "Valakut Awakening // Valakut Stoneforge"
+            ]
+        );
+    }
+
+    #[test]
+    fn type_line_matches_faces() {
+        assert_eq!(
+            face_matches("t:/^land$/"),
+            vec!["Valakut Awakening // Valakut Stoneforge"]
+        );
+        assert_eq!(
+            face_matches("t:insect"),
+            vec!["Delver of Secrets // Insectile Aberration"]
+        );
+    }
+
+    #[test]
+    fn stats_match_faces() {
+        assert_eq!(
+            face_matches("pow>=3"),
+            vec!["Delver of Secrets // Insectile Aberration"]
+        );
+        assert_eq!(
+            face_matches("tou=2"),
+            vec!["Delver of Secrets // Insectile Aberration", "Grizzly Bears"]
+        );
+    }
+
+    fn stat_matches(query: &str) -> Vec<String> {
+        matching_names(
+            "INSERT INTO cards (id, name, object, power, toughness) VALUES
+                ('ten', 'Ten', 'card', '10', '10'),
+                ('star', 'Star', 'card', '*', '*'),
+                ('star-bonus', 'Star Bonus', 'card', '1+*', '1+*'),
+                ('x', 'X', 'card', 'X', 'X'),
+                ('none', 'None', 'card', NULL, NULL);",
+            query,
+        )
+    }
+
+    #[test]
+    fn stats_compare_numerically_not_as_text() {
+        assert_eq!(stat_matches("pow>3"), vec!["Ten"]);
+        assert_eq!(stat_matches("pow<3"), vec!["Star", "Star Bonus", "X"]);
+    }
+
+    #[test]
+    fn star_stats_compare_as_zero() {
+        assert_eq!(stat_matches("pow=0"), vec!["Star", "X"]);
+        assert_eq!(
+            stat_matches("pow=tou"),
+            vec!["Star", "Star Bonus", "Ten", "X"]
+        );
+        assert_eq!(stat_matches("pow>0"), vec!["Star Bonus", "Ten"]);
+    }
+
+    #[test]
+    fn star_with_bonus_compares_as_its_leading_number() {
+        assert_eq!(stat_matches("pow=1"), vec!["Star Bonus"]);
+        assert_eq!(stat_matches("pt=2"), vec!["Star Bonus"]);
+    }
+
+    #[test]
+    fn x_stats_compare_as_zero() {
+        assert_eq!(stat_matches("tou<1"), vec!["Star", "X"]);
+    }
+
+    #[test]
+    fn missing_stats_never_match() {
+        assert!(!stat_matches("pow>=0").contains(&"None".to_string()));
+        assert!(!stat_matches("pow<=0").contains(&"None".to_string()));
+    }
+
+    #[test]
+    fn format_is_legal_or_restricted() {
+        let sql = search::search("f:vintage").unwrap().to_sql();
+        let alias = sql.where_clauses.split('.').next().unwrap().to_string();
+        assert_eq!(
+            sql.where_clauses,
+            format!("{alias}.legality IN ('legal', 'restricted')")
+        );
+        assert_eq!(
+            sql.joins(),
+            format!("LEFT JOIN card_legalities {alias} ON cards.id = {alias}.card_id AND {alias}.format = 'vintage'")
+        );
+        assert!(sql.params.is_empty());
+    }
+
+    #[test]
+    fn banned_in_format() {
+        let sql = search::search("banned:legacy").unwrap().to_sql();
+        assert!(sql.where_clauses.ends_with(".legality IN ('banned')"));
+        assert!(sql.joins().ends_with(".format = 'legacy'"));
+        assert!(sql.params.is_empty());
+    }
+
+    #[test]
+    fn set_code_is_a_param() {
+        let actual = where_and_params("e:WAR");
+        let expected = (
+            "(cards.set_code = ?)".to_string(),
+            vec![Value::Text("war".to_string())],
+        );
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn id_wubrg_greater_than_or_equal() {
-        let actual = search::search("id>=wubrg").unwrap().to_sql().where_clauses;
-        // This isn't really right, but it'll do for now. I kinda doubt magic
-        // will add more colors.
-        let expected =
-            "cards.B=TRUE AND cards.G=TRUE AND cards.R=TRUE AND cards.U=TRUE AND cards.W=TRUE";
+    fn set_type_is_a_param() {
+        let actual = where_and_params("st:masters");
+        let expected = (
+            "(cards.set_type = ?)".to_string(),
+            vec![Value::Text("masters".to_string())],
+        );
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn id_rakdos_equal() {
-        let actual = search::search("id=rakdos").unwrap().to_sql().where_clauses;
-        let expected =
-            "cards.B=TRUE AND cards.R=TRUE AND NOT (cards.G=TRUE OR cards.U=TRUE OR cards.W=TRUE)";
+    fn collector_number_exact() {
+        let actual = where_and_params("cn:12a");
+        let expected = (
+            "(cards.collector_number=?)".to_string(),
+            vec![Value::Text("12a".to_string())],
+        );
         assert_eq!(actual, expected);
     }
 
     #[test]
-    fn id_rakdos_less_than() {
-        let actual = search::search("id<rakdos").unwrap().to_sql().where_clauses;
-        let expected = "(cards.B=TRUE OR cards.R=TRUE) AND (NOT (cards.B=TRUE AND cards.R=TRUE)) AND (NOT (cards.G=TRUE OR cards.U=TRUE OR cards.W=TRUE))";
+    fn collector_number_range() {
+        let actual = where_and_params("number>50");
+        let expected = (
+            "(CAST(cards.collector_number AS INTEGER)>CAST(? AS INTEGER))".to_string(),
+            vec![Value::Text("50".to_string())],
+        );
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn rarity_at_least_rare() {
+        let actual = where_and_params("r>=rare");
+        let expected = "(cards.rarity IN ('rare', 'mythic'))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn rarity_below_mythic() {
+        let actual = where_and_params("r<mythic");
+        let expected = "(cards.rarity IN ('common', 'uncommon', 'rare'))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn rarity_exactly_special() {
+        let actual = where_and_params("r:special");
+        let expected = "(cards.rarity IN ('special'))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn rarity_below_rare() {
+        let actual = where_and_params("rarity<r");
+        let expected = "(cards.rarity IN ('common', 'uncommon'))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
+
+    #[test]
+    fn rarity_exactly_common() {
+        let actual = where_and_params("r:c");
+        let expected = "(cards.rarity IN ('common'))";
+        assert_eq!(actual, (expected.to_string(), vec![]));
+    }
 }
@@ -17,6 +17,8 @@ INSERT OR REPLACE INTO cards (
     cmc,
     power,
     toughness,
+    loyalty,
+    defense,
     flavor_text,
     oracle_text,
     C,
@@ -26,7 +28,28 @@ INSERT OR REPLACE INTO cards (
     R,
     G,
     type_line,
-    mana_cost
+    mana_cost,
+    set_code,
+    set_name,
+    set_type,
+    collector_number,
+    rarity,
+    artist,
+    watermark,
+    released_at,
+    printed_name,
+    printed_text,
+    reprint,
+    digital,
+    border_color,
+    frame,
+    full_art,
+    highres_image,
+    security_stamp,
+    edhrec_rank,
+    promo,
+    illustration_id,
+    color_indicator
 ) VALUES (
     :id,
     :name,
@@ -46,6 +69,8 @@ INSERT OR REPLACE INTO cards (
     :cmc,
     :power,
     :toughness,
+    :loyalty,
+    :defense,
     :flavor_text,
     :oracle_text,
     :C,
@@ -55,5 +80,26 @@ INSERT OR REPLACE INTO cards (
     :R,
     :G,
     :type_line,
-    :mana_cost
+    :mana_cost,
+    :set_code,
+    :set_name,
+    :set_type,
+    :collector_number,
+    :rarity,
+    :artist,
+    :watermark,
+    :released_at,
+    :printed_name,
+    :printed_text,
+    :reprint,
+    :digital,
+    :border_color,
+    :frame,
+    :full_art,
+    :highres_image,
+    :security_stamp,
+    :edhrec_rank,
+    :promo,
+    :illustration_id,
+    :color_indicator
 )
@@ -20,6 +20,7 @@ INSERT OR REPLACE INTO card_faces (
     toughness,
     type_line,
     watermark,
+    color_indicator,
     C,
     W,
     U,
@@ -49,6 +50,7 @@ VALUES (
     :toughness,
     :type_line,
     :watermark,
+    :color_indicator,
     :C,
     :W,
     :U,
@@ -0,0 +1,7 @@
+INSERT OR REPLACE INTO card_finishes (
+    card_id,
+    finish
+) VALUES (
+    :card_id,
+    :finish
+)
\ No newline at end of file
@@ -0,0 +1,7 @@
+INSERT OR REPLACE INTO card_frame_effects (
+    card_id,
+    frame_effect
+) VALUES (
+    :card_id,
+    :frame_effect
+)
\ No newline at end of file
@@ -0,0 +1,7 @@
+INSERT OR REPLACE INTO card_games (
+    card_id,
+    game
+) VALUES (
+    :card_id,
+    :game
+)
\ No newline at end of file
@@ -0,0 +1,9 @@
+INSERT OR REPLACE INTO card_legalities (
+    card_id,
+    format,
+    legality
+) VALUES (
+    :card_id,
+    :format,
+    :legality
+)
\ No newline at end of file
@@ -0,0 +1,17 @@
+INSERT OR REPLACE INTO card_prices (
+    card_id,
+    usd,
+    usd_foil,
+    usd_etched,
+    eur,
+    eur_foil,
+    tix
+) VALUES (
+    :card_id,
+    :usd,
+    :usd_foil,
+    :usd_etched,
+    :eur,
+    :eur_foil,
+    :tix
+)
\ No newline at end of file
@@ -0,0 +1,7 @@
+INSERT OR REPLACE INTO card_produced_mana (
+    card_id,
+    produced_mana
+) VALUES (
+    :card_id,
+    :produced_mana
+)
\ No newline at end of file
@@ -0,0 +1,27 @@
+DELETE FROM oracle_prints;
+INSERT INTO oracle_prints (
+    oracle_id,
+    prints,
+    sets,
+    paper_prints,
+    paper_sets
+)
+SELECT
+    oracle_id,
+    COUNT(*),
+    COUNT(DISTINCT set_code),
+    COUNT(CASE WHEN paper THEN 1 END),
+    COUNT(DISTINCT CASE WHEN paper THEN set_code END)
+FROM (
+    SELECT
+        -- Reversible cards only have an oracle id on their faces.
+        IFNULL(
+            cards.oracle_id,
+            (SELECT card_faces.oracle_id FROM card_faces WHERE card_faces.card_id = cards.id ORDER BY card_faces.face_index LIMIT 1)
+        ) AS oracle_id,
+        cards.set_code,
+        EXISTS (SELECT 1 FROM card_games WHERE card_games.card_id = cards.id AND card_games.game = 'paper') AS paper
+    FROM cards
+)
+WHERE oracle_id IS NOT NULL
+GROUP BY oracle_id
\ No newline at end of file
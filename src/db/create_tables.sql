@@ -17,6 +17,8 @@ CREATE TABLE IF NOT EXISTS cards (
     cmc DECIMAL(32,16),
     power TEXT,
     toughness TEXT,
+    loyalty TEXT,
+    defense TEXT,
     flavor_text TEXT,
     oracle_text TEXT,
     C BOOLEAN DEFAULT 0,
@@ -27,9 +29,32 @@ CREATE TABLE IF NOT EXISTS cards (
     G BOOLEAN DEFAULT 0,
     type_line TEXT,
     mana_cost TEXT,
+    set_code TEXT,
+    set_name TEXT,
+    set_type TEXT,
+    collector_number TEXT,
+    rarity TEXT,
+    artist TEXT,
+    watermark TEXT,
+    released_at TEXT,
+    printed_name TEXT,
+    printed_text TEXT,
+    reprint BOOLEAN,
+    digital BOOLEAN,
+    border_color TEXT,
+    frame TEXT,
+    full_art BOOLEAN,
+    highres_image BOOLEAN,
+    security_stamp TEXT,
+    edhrec_rank INTEGER,
+    promo BOOLEAN,
+    illustration_id TEXT,
+    color_indicator TEXT,
     image BLOB
 );
 
+CREATE INDEX IF NOT EXISTS cards_oracle_id ON cards(oracle_id);
+
 CREATE TABLE IF NOT EXISTS card_faces (
     face_index INTEGER NOT NULL,
     card_id TEXT,
@@ -52,6 +77,7 @@ CREATE TABLE IF NOT EXISTS card_faces (
     toughness TEXT,
     type_line TEXT,
     watermark TEXT,
+    color_indicator TEXT,
     C BOOLEAN DEFAULT 0,
     W BOOLEAN DEFAULT 0,
     U BOOLEAN DEFAULT 0,
@@ -74,12 +100,51 @@ CREATE TABLE IF NOT EXISTS card_image_uris (
     FOREIGN KEY (card_id) REFERENCES cards(id)
 );
 
+CREATE TABLE IF NOT EXISTS card_prices (
+    card_id TEXT PRIMARY KEY,
+    usd REAL,
+    usd_foil REAL,
+    usd_etched REAL,
+    eur REAL,
+    eur_foil REAL,
+    tix REAL,
+    FOREIGN KEY (card_id) REFERENCES cards(id)
+);
+
 CREATE TABLE IF NOT EXISTS card_color_identity (
     card_id TEXT,
     color_identity TEXT,
     FOREIGN KEY (card_id) REFERENCES cards(id)
 );
 
+CREATE TABLE IF NOT EXISTS card_produced_mana (
+    card_id TEXT,
+    produced_mana TEXT,
+    PRIMARY KEY (card_id, produced_mana),
+    FOREIGN KEY (card_id) REFERENCES cards(id)
+);
+
+CREATE TABLE IF NOT EXISTS card_games (
+    card_id TEXT,
+    game TEXT,
+    PRIMARY KEY (card_id, game),
+    FOREIGN KEY (card_id) REFERENCES cards(id)
+);
+
+CREATE TABLE IF NOT EXISTS card_finishes (
+    card_id TEXT,
+    finish TEXT,
+    PRIMARY KEY (card_id, finish),
+    FOREIGN KEY (card_id) REFERENCES cards(id)
+);
+
+CREATE TABLE IF NOT EXISTS card_frame_effects (
+    card_id TEXT,
+    frame_effect TEXT,
+    PRIMARY KEY (card_id, frame_effect),
+    FOREIGN KEY (card_id) REFERENCES cards(id)
+);
+
 CREATE TABLE IF NOT EXISTS card_keywords (
     card_id TEXT,
     keyword TEXT,
@@ -96,4 +161,22 @@ CREATE TABLE IF NOT EXISTS card_faces_image_uris (
     art_crop TEXT,
     border_crop TEXT,
     FOREIGN KEY (card_id, face_index) REFERENCES card_faces(card_id, face_index)
-);
\ No newline at end of file
+);
+
+CREATE TABLE IF NOT EXISTS card_legalities (
+    card_id TEXT,
+    format TEXT,
+    legality TEXT,
+    PRIMARY KEY (card_id, format),
+    FOREIGN KEY (card_id) REFERENCES cards(id)
+);
+
+-- How many times each card has been printed, built by update-database once
+-- every card has been added.
+CREATE TABLE IF NOT EXISTS oracle_prints (
+    oracle_id TEXT PRIMARY KEY,
+    prints INTEGER NOT NULL,
+    sets INTEGER NOT NULL,
+    paper_prints INTEGER NOT NULL,
+    paper_sets INTEGER NOT NULL
+);
@@ -22,3 +22,17 @@ pub static ADD_CARD_FACE: &str = include_str!("add_card_face.sql");
 pub static ADD_CARD_FACE_COLORS: &str = include_str!("add_card_face_colors.sql");
 #[allow(dead_code)]
 pub static ADD_CARD_FACE_IMAGE_URIS: &str = include_str!("add_card_face_image_uris.sql");
+#[allow(dead_code)]
+pub static ADD_CARD_LEGALITIES: &str = include_str!("add_card_legalities.sql");
+#[allow(dead_code)]
+pub static ADD_CARD_PRODUCED_MANA: &str = include_str!("add_card_produced_mana.sql");
+#[allow(dead_code)]
+pub static ADD_CARD_FINISHES: &str = include_str!("add_card_finishes.sql");
+#[allow(dead_code)]
+pub static ADD_CARD_FRAME_EFFECTS: &str = include_str!("add_card_frame_effects.sql");
+#[allow(dead_code)]
+pub static ADD_CARD_GAMES: &str = include_str!("add_card_games.sql");
+#[allow(dead_code)]
+pub static ADD_CARD_PRICES: &str = include_str!("add_card_prices.sql");
+#[allow(dead_code)]
+pub static BUILD_ORACLE_PRINTS: &str = include_str!("build_oracle_prints.sql");
@@ -6,7 +6,7 @@ mod db;
 mod search;
 mod types;
 
-use std::{collections::HashSet, iter};
+use std::{collections::HashSet, iter, path::PathBuf};
 
 use card::Card;
 use cards::Cards;
@@ -261,6 +261,12 @@ impl Application for MagicalSearch {
 }
 
 pub fn main() -> iced::Result {
+    search::shortcut::load_built_in();
+    // Shortcuts the user has defined are optional, so a bad file shouldn't
+    // stop the app from starting.
+    if let Err(err) = search::shortcut::load_file(&PathBuf::from("target").join("shortcuts.txt")) {
+        eprintln!("{err}");
+    }
     let mut settings = Settings::default();
     settings.window.size.height = settings.window.size.height + 200.0;
     settings.window.size.width = settings.window.size.height + 400.0;
@@ -0,0 +1,96 @@
+use nom::{branch::alt, character::complete::digit1, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{
+    name::quoted_or_until_space,
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// Search for words in a card's artist name using the a: or artist: keywords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtistQuery {
+    pub artist: String,
+}
+
+pub fn artist_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((tag_no_case("artist:"), tag_no_case("a:"))),
+        quoted_or_until_space,
+    ))
+    .map(|(_, artist)| ArtistQuery {
+        artist: artist.to_string(),
+    })
+    .map(ParsedSearch::artist_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn artist_query(artist_query: ArtistQuery) -> Self {
+        Self::Keyword(SearchKeyword::ArtistQuery(artist_query))
+    }
+}
+
+// Find cards illustrated by more than one artist with artists>1, counting the
+// distinct artists across all of a card's faces.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtistsQuery {
+    pub operator: NumericOperator,
+    pub count: String,
+}
+
+pub fn artists_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("artists"), numeric_operator, digit1))
+        .map(|(_, operator, count)| ArtistsQuery {
+            operator,
+            count: count.to_string(),
+        })
+        .map(ParsedSearch::artists_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn artists_query(artists_query: ArtistsQuery) -> Self {
+        Self::Keyword(SearchKeyword::ArtistsQuery(artists_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn artist_quoted() {
+        let (_, actual) = artist_query(r#"a:"John Avon""#).unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::artist_query(ArtistQuery {
+                artist: "John Avon".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn artist_long_keyword() {
+        let (_, actual) = artist_query("artist:avon").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::artist_query(ArtistQuery {
+                artist: "avon".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn artists_greater_than_one() {
+        let (_, actual) = artists_query("artists>1").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::artists_query(ArtistsQuery {
+                operator: NumericOperator::GreaterThan,
+                count: "1".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use nom::{sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{
+    name::quoted_or_until_space,
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// Use cn: or number: to find cards by collector number within a set. Searching
+// by ranges with a syntax like cn>50 is also possible.
+//
+// Collector numbers can contain letters or symbols like ★, so : and = match the
+// collector number exactly while the other operators compare using its leading
+// number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectorNumberQuery {
+    pub operator: NumericOperator,
+    pub operand: String,
+}
+
+pub fn collector_number_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        tag_no_case("number").or(tag_no_case("cn")),
+        numeric_operator,
+        quoted_or_until_space,
+    ))
+    .map(|(_, operator, operand)| CollectorNumberQuery {
+        operator,
+        operand: operand.to_string(),
+    })
+    .map(ParsedSearch::collector_number_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn collector_number_query(collector_number_query: CollectorNumberQuery) -> Self {
+        Self::Keyword(SearchKeyword::CollectorNumberQuery(collector_number_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl CollectorNumberQuery {
+        pub fn new(operator: NumericOperator, operand: &str) -> Self {
+            Self {
+                operator,
+                operand: operand.to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cn_greater_than_50() {
+        let (_, actual) = collector_number_query("cn>50").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::collector_number_query(CollectorNumberQuery::new(
+                NumericOperator::GreaterThan,
+                "50"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_number_with_letter() {
+        let (_, actual) = collector_number_query("number:12a").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::collector_number_query(CollectorNumberQuery::new(
+                NumericOperator::Colon,
+                "12a"
+            ))
+        );
+    }
+}
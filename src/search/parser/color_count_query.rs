@@ -0,0 +1,108 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, character::complete::digit1, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// Whether a card's colors or its color identity are being counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorCount {
+    Color,
+    Identity,
+}
+
+impl ColorCount {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Color => "number of colors",
+            Self::Identity => "number of colors in its identity",
+        }
+    }
+}
+
+impl Display for ColorCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Color => write!(f, "c"),
+            Self::Identity => write!(f, "id"),
+        }
+    }
+}
+
+// Numbers can be used in place of colors with c: and id: to compare how many
+// colors a card has, e.g. c=2 for two-color cards or id>=3 for cards with at
+// least three colors in their identity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorCountQuery {
+    pub count: ColorCount,
+    pub operator: NumericOperator,
+    pub operand: String,
+}
+
+fn color_count(input: &str) -> IResult<&str, ColorCount, ErrorTree<&str>> {
+    alt((
+        tag_no_case("color").value(ColorCount::Color),
+        tag_no_case("c").value(ColorCount::Color),
+        tag_no_case("identity").value(ColorCount::Identity),
+        tag_no_case("id").value(ColorCount::Identity),
+    ))
+    .parse(input)
+}
+
+pub fn color_count_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((color_count, numeric_operator, digit1))
+        .map(|(count, operator, operand)| ColorCountQuery {
+            count,
+            operator,
+            operand: operand.to_string(),
+        })
+        .map(ParsedSearch::color_count_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn color_count_query(color_count_query: ColorCountQuery) -> Self {
+        Self::Keyword(SearchKeyword::ColorCountQuery(color_count_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_equals_two() {
+        let (_, actual) = color_count_query("c=2").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::color_count_query(ColorCountQuery {
+                count: ColorCount::Color,
+                operator: NumericOperator::Equal,
+                operand: "2".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn identity_at_least_three() {
+        let (_, actual) = color_count_query("identity>=3").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::color_count_query(ColorCountQuery {
+                count: ColorCount::Identity,
+                operator: NumericOperator::GreaterThanOrEqual,
+                operand: "3".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn colors_are_not_counts() {
+        assert!(color_count_query("c:rg").is_err());
+    }
+}
@@ -44,7 +44,7 @@ pub struct ColorQuery {
     pub operand: ColorOperand,
 }
 
-fn color_operator(input: &str) -> IResult<&str, ColorOperator, ErrorTree<&str>> {
+pub fn color_operator(input: &str) -> IResult<&str, ColorOperator, ErrorTree<&str>> {
     alt((
         tag("!=").value(ColorOperator::NotEqual),
         tag("<=").value(ColorOperator::LessThanOrEqual),
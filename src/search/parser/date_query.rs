@@ -0,0 +1,151 @@
+use std::fmt::{self, Display, Formatter};
+
+use chrono::NaiveDate;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::digit1,
+    combinator::recognize,
+    sequence::tuple,
+    IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// You can use year to find cards released relative to a year, e.g. year>=2015.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearQuery {
+    pub operator: NumericOperator,
+    pub year: String,
+}
+
+// A date is either written out as yyyy-mm-dd or is the code of a set that
+// stands in for that set's release date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateOperand {
+    Date(NaiveDate),
+    Set(String),
+}
+
+impl Display for DateOperand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date(date) => write!(f, "{date}"),
+            Self::Set(set_code) => write!(f, "{set_code}"),
+        }
+    }
+}
+
+// You can use date to find cards released relative to a specific date, or
+// relative to when a set was released, e.g. date>=2015-08-18 or date>ori.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateQuery {
+    pub operator: NumericOperator,
+    pub operand: DateOperand,
+}
+
+pub fn year_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("year"), numeric_operator, digit1))
+        .map(|(_, operator, year): (_, _, &str)| YearQuery {
+            operator,
+            year: year.to_string(),
+        })
+        .map(ParsedSearch::year_query)
+        .parse(input)
+}
+
+// Something shaped like a date that isn't a real date, like 2015-13-01, is an
+// error rather than a set code.
+fn date(input: &str) -> IResult<&str, NaiveDate, ErrorTree<&str>> {
+    recognize(tuple((digit1, tag("-"), digit1, tag("-"), digit1)))
+        .map_res_cut(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d"))
+        .parse(input)
+}
+
+fn date_operand(input: &str) -> IResult<&str, DateOperand, ErrorTree<&str>> {
+    alt((
+        date.map(DateOperand::Date),
+        take_while1(|c: char| c.is_alphanumeric())
+            .map(|set_code: &str| DateOperand::Set(set_code.to_lowercase())),
+    ))
+    .parse(input)
+}
+
+pub fn date_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("date"), numeric_operator, date_operand))
+        .map(|(_, operator, operand)| DateQuery { operator, operand })
+        .map(ParsedSearch::date_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn year_query(year_query: YearQuery) -> Self {
+        Self::Keyword(SearchKeyword::YearQuery(year_query))
+    }
+    pub fn date_query(date_query: DateQuery) -> Self {
+        Self::Keyword(SearchKeyword::DateQuery(date_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl YearQuery {
+        pub fn new(operator: NumericOperator, year: &str) -> Self {
+            Self {
+                operator,
+                year: year.to_string(),
+            }
+        }
+    }
+
+    impl DateQuery {
+        pub fn new(operator: NumericOperator, operand: DateOperand) -> Self {
+            Self { operator, operand }
+        }
+    }
+
+    #[test]
+    fn year_greater_than_or_equal() {
+        let (_, actual) = year_query("year>=2015").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::year_query(YearQuery::new(NumericOperator::GreaterThanOrEqual, "2015"))
+        );
+    }
+
+    #[test]
+    fn date_with_iso_date() {
+        let (_, actual) = date_query("date>=2015-08-18").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::date_query(DateQuery::new(
+                NumericOperator::GreaterThanOrEqual,
+                DateOperand::Date(NaiveDate::from_ymd_opt(2015, 8, 18).unwrap())
+            ))
+        );
+    }
+
+    #[test]
+    fn date_with_set_code() {
+        let (_, actual) = date_query("date>ORI").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::date_query(DateQuery::new(
+                NumericOperator::GreaterThan,
+                DateOperand::Set("ori".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn invalid_date_does_not_parse() {
+        assert!(date_query("date>2015-13-01").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{bytes::complete::tag_no_case, combinator::map_opt, sequence::tuple, IResult, Parser};
+use nom_supreme::error::ErrorTree;
+
+use super::{
+    mana_cost::{mana_cost, ManaColor, ManaCost, ManaSymbol},
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// You can find permanents that provide specific levels of devotion, using
+// either single-color mana symbols for devotion to one color, or hybrid symbols
+// for devotion to two, with devotion: or a comparison operator.
+//
+// devotion:{u/b}{u/b}{u/b} is devotion to black and blue equal to 3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DevotionQuery {
+    pub operator: NumericOperator,
+    pub colors: Vec<ManaColor>,
+    pub count: u32,
+}
+
+impl DevotionQuery {
+    // The operand has to repeat a single colored or hybrid symbol, e.g. {G}{G}
+    // or {u/b}{u/b}, since that's what says which colors are being counted.
+    fn from_mana_cost(operator: NumericOperator, mana_cost: ManaCost) -> Option<Self> {
+        let mut symbols = mana_cost.symbols();
+        let (symbol, count) = symbols.next()?;
+        if symbols.next().is_some() {
+            return None;
+        }
+        let colors = match symbol {
+            ManaSymbol::Colored(color) => vec![*color],
+            ManaSymbol::Hybrid(first, second) => vec![*first, *second],
+            _ => return None,
+        };
+        Some(Self {
+            operator,
+            colors,
+            count,
+        })
+    }
+
+    pub fn colors(&self) -> String {
+        self.colors.iter().map(ManaColor::to_string).collect()
+    }
+}
+
+impl Display for DevotionQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let symbol = match self.colors.as_slice() {
+            [first, second] => ManaSymbol::Hybrid(*first, *second),
+            [color] => ManaSymbol::Colored(*color),
+            _ => return Err(fmt::Error),
+        };
+        write!(f, "devotion{}", self.operator)?;
+        (0..self.count).try_for_each(|_| write!(f, "{}", symbol))
+    }
+}
+
+pub fn devotion_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    map_opt(
+        tuple((tag_no_case("devotion"), numeric_operator, mana_cost)),
+        |(_, operator, mana_cost)| DevotionQuery::from_mana_cost(operator, mana_cost),
+    )
+    .map(ParsedSearch::devotion_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn devotion_query(devotion_query: DevotionQuery) -> Self {
+        Self::Keyword(SearchKeyword::DevotionQuery(devotion_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn devotion_to_two_colors() {
+        let (_, actual) = devotion_query("devotion:{u/b}{u/b}{u/b}").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::devotion_query(DevotionQuery {
+                operator: NumericOperator::Colon,
+                colors: vec![ManaColor::Blue, ManaColor::Black],
+                count: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn devotion_to_one_color() {
+        let (_, actual) = devotion_query("devotion>=GG").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::devotion_query(DevotionQuery {
+                operator: NumericOperator::GreaterThanOrEqual,
+                colors: vec![ManaColor::Green],
+                count: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn devotion_needs_a_single_symbol() {
+        assert!(devotion_query("devotion:{G}{U}").is_err());
+        assert!(devotion_query("devotion:{2}").is_err());
+    }
+}
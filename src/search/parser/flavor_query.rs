@@ -0,0 +1,57 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{
+    name::{text_operand, TextOperand},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// Search for words in a card's flavor text using the ft: or flavor: keywords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlavorQuery {
+    pub flavor_text: TextOperand,
+}
+
+pub fn flavor_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((tag_no_case("flavor:"), tag_no_case("ft:"))),
+        text_operand,
+    ))
+    .map(|(_, flavor_text)| FlavorQuery { flavor_text })
+    .map(ParsedSearch::flavor_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn flavor_query(flavor_query: FlavorQuery) -> Self {
+        Self::Keyword(SearchKeyword::FlavorQuery(flavor_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flavor_text_word() {
+        let (_, actual) = flavor_query("ft:mishra").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::flavor_query(FlavorQuery {
+                flavor_text: TextOperand::Text("mishra".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn flavor_text_regex() {
+        let (_, actual) = flavor_query(r"flavor:/\bmishra\b/").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::flavor_query(FlavorQuery {
+                flavor_text: TextOperand::Regex(r"\bmishra\b".to_string())
+            })
+        );
+    }
+}
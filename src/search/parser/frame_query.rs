@@ -0,0 +1,118 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{name::quoted_or_until_space, parsed_search::SearchKeyword, ParsedSearch};
+
+// Use the border: keyword to find cards with a black, white, silver, gold or
+// borderless border.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BorderQuery {
+    pub border: String,
+}
+
+// You can find cards with a specific frame edition using frame:1993,
+// frame:1997, frame:2003, frame:2015, and frame:future, or with a particular
+// frame effect, such as frame:legendary, frame:colorshifted or
+// frame:tombstone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameQuery {
+    pub frame: String,
+}
+
+// Search for a card's security stamp with stamp:oval, stamp:acorn,
+// stamp:triangle, or stamp:arena.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StampQuery {
+    pub stamp: String,
+}
+
+pub fn border_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("border:"), quoted_or_until_space))
+        .map(|(_, border)| BorderQuery {
+            border: border.to_lowercase(),
+        })
+        .map(ParsedSearch::border_query)
+        .parse(input)
+}
+
+pub fn frame_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("frame:"), quoted_or_until_space))
+        .map(|(_, frame)| FrameQuery {
+            frame: frame.to_lowercase(),
+        })
+        .map(ParsedSearch::frame_query)
+        .parse(input)
+}
+
+pub fn stamp_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((tag_no_case("stamp:"), tag_no_case("security_stamp:"))),
+        quoted_or_until_space,
+    ))
+    .map(|(_, stamp)| StampQuery {
+        stamp: stamp.to_lowercase(),
+    })
+    .map(ParsedSearch::stamp_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn border_query(border_query: BorderQuery) -> Self {
+        Self::Keyword(SearchKeyword::BorderQuery(border_query))
+    }
+    pub fn frame_query(frame_query: FrameQuery) -> Self {
+        Self::Keyword(SearchKeyword::FrameQuery(frame_query))
+    }
+    pub fn stamp_query(stamp_query: StampQuery) -> Self {
+        Self::Keyword(SearchKeyword::StampQuery(stamp_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn border_white() {
+        let (_, actual) = border_query("border:White").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::border_query(BorderQuery {
+                border: "white".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn frame_edition() {
+        let (_, actual) = frame_query("frame:2003").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::frame_query(FrameQuery {
+                frame: "2003".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn frame_effect() {
+        let (_, actual) = frame_query("frame:colorshifted").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::frame_query(FrameQuery {
+                frame: "colorshifted".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn stamp_acorn() {
+        let (_, actual) = stamp_query("stamp:acorn").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stamp_query(StampQuery {
+                stamp: "acorn".to_string()
+            })
+        );
+    }
+}
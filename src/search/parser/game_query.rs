@@ -0,0 +1,92 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    Paper,
+    Mtgo,
+    Arena,
+}
+
+impl Game {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Paper => "paper",
+            Self::Mtgo => "Magic Online",
+            Self::Arena => "MTG Arena",
+        }
+    }
+}
+
+// This is how the games are written both in searches and in the games
+// Scryfall lists for each print.
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Paper => write!(f, "paper"),
+            Self::Mtgo => write!(f, "mtgo"),
+            Self::Arena => write!(f, "arena"),
+        }
+    }
+}
+
+pub fn game(input: &str) -> IResult<&str, Game, ErrorTree<&str>> {
+    alt((
+        tag_no_case("paper").value(Game::Paper),
+        tag_no_case("mtgo").value(Game::Mtgo),
+        tag_no_case("arena").value(Game::Arena),
+    ))
+    .parse(input)
+}
+
+// You can find specific prints available in different Magic game environments
+// with the game: keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameQuery {
+    pub game: Game,
+}
+
+pub fn game_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("game:"), game))
+        .map(|(_, game)| GameQuery { game })
+        .map(ParsedSearch::game_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn game_query(game_query: GameQuery) -> Self {
+        Self::Keyword(SearchKeyword::GameQuery(game_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn game_arena() {
+        let (_, actual) = game_query("game:arena").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::game_query(GameQuery { game: Game::Arena })
+        );
+    }
+
+    #[test]
+    fn game_is_case_insensitive() {
+        let (_, actual) = game_query("GAME:MTGO").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::game_query(GameQuery { game: Game::Mtgo })
+        );
+    }
+
+    #[test]
+    fn unknown_game_does_not_parse() {
+        assert!(game_query("game:hearthstone").is_err());
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, character::complete::digit1, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameId {
+    Arena,
+    Mtgo,
+}
+
+impl GameId {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Arena => "Arena id",
+            Self::Mtgo => "Magic Online id",
+        }
+    }
+}
+
+impl Display for GameId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Arena => write!(f, "arenaid"),
+            Self::Mtgo => write!(f, "mtgoid"),
+        }
+    }
+}
+
+// Look up the print with a specific id in one of the digital games using
+// arenaid: or mtgoid:.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdQuery {
+    pub game_id: GameId,
+    pub id: String,
+}
+
+pub fn id_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((
+            tag_no_case("arenaid:").value(GameId::Arena),
+            tag_no_case("mtgoid:").value(GameId::Mtgo),
+        )),
+        digit1,
+    ))
+    .map(|(game_id, id): (_, &str)| IdQuery {
+        game_id,
+        id: id.to_string(),
+    })
+    .map(ParsedSearch::id_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn id_query(id_query: IdQuery) -> Self {
+        Self::Keyword(SearchKeyword::IdQuery(id_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arena_id() {
+        let (_, actual) = id_query("arenaid:67330").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::id_query(IdQuery {
+                game_id: GameId::Arena,
+                id: "67330".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn mtgo_id_must_be_a_number() {
+        assert!(id_query("mtgoid:abc").is_err());
+    }
+}
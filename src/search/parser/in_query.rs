@@ -0,0 +1,60 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{
+    game_query::{game, Game},
+    language_query::language,
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// The in: keyword finds cards that have ever been printed somewhere, no matter
+// which print of the card is being looked at. For example, in:ru finds cards
+// that have been printed in Russian at least once, and in:arena finds cards
+// with a print available on Arena.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InQuery {
+    Language(String),
+    Game(Game),
+}
+
+pub fn in_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        tag_no_case("in:"),
+        alt((game.map(InQuery::Game), language.map(InQuery::Language))),
+    ))
+    .map(|(_, in_query)| in_query)
+    .map(ParsedSearch::in_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn in_query(in_query: InQuery) -> Self {
+        Self::Keyword(SearchKeyword::InQuery(in_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_language() {
+        let (_, actual) = in_query("in:zhs").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::in_query(InQuery::Language("zhs".to_string()))
+        );
+    }
+
+    #[test]
+    fn in_game() {
+        let (_, actual) = in_query("in:arena").unwrap();
+        assert_eq!(actual, ParsedSearch::in_query(InQuery::Game(Game::Arena)));
+    }
+
+    #[test]
+    fn in_unknown_does_not_parse() {
+        assert!(in_query("in:nowhere").is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use nom::{sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+// Vanguard, plane, scheme, and phenomenon cards are hidden by default, as are
+// tokens, emblems, art series cards and cards from “memorabilia” sets. Use
+// include:extras to reveal absolutely every card when you search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncludeQuery {
+    Extras,
+}
+
+pub fn include_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        tag_no_case("include:"),
+        tag_no_case("extras").value(IncludeQuery::Extras),
+    ))
+    .map(|(_, include_query)| include_query)
+    .map(ParsedSearch::include_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn include_query(include_query: IncludeQuery) -> Self {
+        Self::Keyword(SearchKeyword::IncludeQuery(include_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn include_extras() {
+        let (_, actual) = include_query("include:Extras").unwrap();
+        assert_eq!(actual, ParsedSearch::include_query(IncludeQuery::Extras));
+    }
+
+    #[test]
+    fn include_unknown_does_not_parse() {
+        assert!(include_query("include:everything").is_err());
+    }
+}
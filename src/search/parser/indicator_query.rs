@@ -0,0 +1,32 @@
+use nom::{IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+// Find cards that have a color indicator with has:indicator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndicatorQuery;
+
+pub fn indicator_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tag_no_case("has:indicator")
+        .map(|_| IndicatorQuery)
+        .map(ParsedSearch::indicator_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn indicator_query(indicator_query: IndicatorQuery) -> Self {
+        Self::Keyword(SearchKeyword::IndicatorQuery(indicator_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn has_indicator() {
+        let (_, actual) = indicator_query("has:Indicator").unwrap();
+        assert_eq!(actual, ParsedSearch::indicator_query(IndicatorQuery));
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{
+    branch::alt, bytes::complete::take_while1, combinator::map_opt, sequence::tuple, IResult,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{mana_cost::ManaSymbolKind, parsed_search::SearchKeyword, ParsedSearch};
+
+// The named predicates that can be used with is: and not:
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsPredicate {
+    Split,
+    Flip,
+    Transform,
+    Meld,
+    Leveler,
+    Dfc,
+    Mdfc,
+    Spell,
+    Permanent,
+    Historic,
+    Vanilla,
+    FrenchVanilla,
+    Bear,
+    Modal,
+    Reprint,
+    Unique,
+    Digital,
+    Foil,
+    Nonfoil,
+    Etched,
+    Glossy,
+    FullArt,
+    Hires,
+    ManaSymbol(ManaSymbolKind),
+}
+
+// The registry of names for each predicate. Some predicates have more than one
+// name, the first one listed is what's used when displaying it.
+static PREDICATES: &[(&str, IsPredicate)] = &[
+    ("split", IsPredicate::Split),
+    ("flip", IsPredicate::Flip),
+    ("transform", IsPredicate::Transform),
+    ("meld", IsPredicate::Meld),
+    ("leveler", IsPredicate::Leveler),
+    ("dfc", IsPredicate::Dfc),
+    ("mdfc", IsPredicate::Mdfc),
+    ("spell", IsPredicate::Spell),
+    ("permanent", IsPredicate::Permanent),
+    ("historic", IsPredicate::Historic),
+    ("vanilla", IsPredicate::Vanilla),
+    ("frenchvanilla", IsPredicate::FrenchVanilla),
+    ("bear", IsPredicate::Bear),
+    ("modal", IsPredicate::Modal),
+    ("reprint", IsPredicate::Reprint),
+    ("unique", IsPredicate::Unique),
+    ("digital", IsPredicate::Digital),
+    ("foil", IsPredicate::Foil),
+    ("nonfoil", IsPredicate::Nonfoil),
+    ("etched", IsPredicate::Etched),
+    ("glossy", IsPredicate::Glossy),
+    ("full", IsPredicate::FullArt),
+    ("hires", IsPredicate::Hires),
+    ("hybrid", IsPredicate::ManaSymbol(ManaSymbolKind::Hybrid)),
+    (
+        "phyrexian",
+        IsPredicate::ManaSymbol(ManaSymbolKind::Phyrexian),
+    ),
+];
+
+impl IsPredicate {
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        PREDICATES
+            .iter()
+            .find(|(predicate_name, _)| *predicate_name == name)
+            .map(|(_, predicate)| *predicate)
+    }
+
+    pub fn name(&self) -> &'static str {
+        PREDICATES
+            .iter()
+            .find(|(_, predicate)| predicate == self)
+            .map(|(name, _)| *name)
+            .expect("Every predicate should be in the registry.")
+    }
+}
+
+impl Display for IsPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// The is: keyword has a convenient inverted mode not: which is the same as
+// -is:. Conversely, -not: is the same as is:.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsQuery {
+    pub predicate: IsPredicate,
+    pub negated: bool,
+}
+
+fn predicate(input: &str) -> IResult<&str, IsPredicate, ErrorTree<&str>> {
+    map_opt(
+        take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+        IsPredicate::from_name,
+    )
+    .parse(input)
+}
+
+// Parses the is: or not: prefix, returning whether it's negated.
+pub fn is_or_not(input: &str) -> IResult<&str, bool, ErrorTree<&str>> {
+    alt((
+        tag_no_case("is:").value(false),
+        tag_no_case("not:").value(true),
+    ))
+    .parse(input)
+}
+
+pub fn is_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((is_or_not, predicate))
+        .map(|(negated, predicate)| IsQuery { predicate, negated })
+        .map(ParsedSearch::is_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn is_query(is_query: IsQuery) -> Self {
+        Self::Keyword(SearchKeyword::IsQuery(is_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl IsQuery {
+        pub fn new(predicate: IsPredicate, negated: bool) -> Self {
+            Self { predicate, negated }
+        }
+    }
+
+    #[test]
+    fn is_split() {
+        let (_, actual) = is_query("is:split").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::is_query(IsQuery::new(IsPredicate::Split, false))
+        );
+    }
+
+    #[test]
+    fn not_mdfc() {
+        let (_, actual) = is_query("not:MDFC").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::is_query(IsQuery::new(IsPredicate::Mdfc, true))
+        );
+    }
+
+    #[test]
+    fn is_hybrid() {
+        let (_, actual) = is_query("is:hybrid").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::is_query(IsQuery::new(
+                IsPredicate::ManaSymbol(ManaSymbolKind::Hybrid),
+                false
+            ))
+        );
+    }
+
+    #[test]
+    fn is_frenchvanilla() {
+        let (_, actual) = is_query("is:frenchvanilla").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::is_query(IsQuery::new(IsPredicate::FrenchVanilla, false))
+        );
+    }
+
+    #[test]
+    fn unknown_predicate_does_not_parse() {
+        assert!(is_query("is:splits").is_err());
+        assert!(is_query("is:nonsense").is_err());
+    }
+
+    #[test]
+    fn every_predicate_round_trips_through_its_name() {
+        for (name, predicate) in PREDICATES {
+            assert_eq!(IsPredicate::from_name(name), Some(*predicate));
+        }
+    }
+}
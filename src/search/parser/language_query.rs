@@ -0,0 +1,108 @@
+use nom::{
+    branch::alt, bytes::complete::take_while1, combinator::map_opt, sequence::tuple, IResult,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+// The languages Scryfall prints cards in, as the code stored on each print
+// along with a name that can be used in its place.
+static LANGUAGES: &[(&str, &str)] = &[
+    ("en", "english"),
+    ("es", "spanish"),
+    ("fr", "french"),
+    ("de", "german"),
+    ("it", "italian"),
+    ("pt", "portuguese"),
+    ("ja", "japanese"),
+    ("ko", "korean"),
+    ("ru", "russian"),
+    ("zhs", "simplified_chinese"),
+    ("zht", "traditional_chinese"),
+    ("he", "hebrew"),
+    ("la", "latin"),
+    ("grc", "ancient_greek"),
+    ("ar", "arabic"),
+    ("sa", "sanskrit"),
+    ("ph", "phyrexian"),
+];
+
+// Finds the language code for either a code or a language's name.
+fn language_code(name: &str) -> Option<String> {
+    let name = name.to_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|(code, language)| *code == name || *language == name)
+        .map(|(code, _)| code.to_string())
+}
+
+pub fn language(input: &str) -> IResult<&str, String, ErrorTree<&str>> {
+    map_opt(
+        take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+        language_code,
+    )
+    .parse(input)
+}
+
+// You can request cards in certain languages with the lang: or language:
+// keywords, and widen a search to every language with lang:any. Searches
+// without either only include English cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LanguageQuery {
+    Language(String),
+    Any,
+}
+
+pub fn language_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((tag_no_case("language:"), tag_no_case("lang:"))),
+        alt((
+            tag_no_case("any").value(LanguageQuery::Any),
+            language.map(LanguageQuery::Language),
+        )),
+    ))
+    .map(|(_, language_query)| language_query)
+    .map(ParsedSearch::language_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn language_query(language_query: LanguageQuery) -> Self {
+        Self::Keyword(SearchKeyword::LanguageQuery(language_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lang_code() {
+        let (_, actual) = language_query("lang:ja").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::language_query(LanguageQuery::Language("ja".to_string()))
+        );
+    }
+
+    #[test]
+    fn language_name() {
+        let (_, actual) = language_query("language:Japanese").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::language_query(LanguageQuery::Language("ja".to_string()))
+        );
+    }
+
+    #[test]
+    fn lang_any() {
+        let (_, actual) = language_query("lang:any").unwrap();
+        assert_eq!(actual, ParsedSearch::language_query(LanguageQuery::Any));
+    }
+
+    #[test]
+    fn unknown_language_does_not_parse() {
+        assert!(language_query("lang:klingon").is_err());
+    }
+}
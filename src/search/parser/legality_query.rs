@@ -0,0 +1,254 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{
+    branch::alt, character::complete::alpha1, combinator::map_opt, sequence::tuple, IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+// Format Legality
+// Use the f: or format: keywords to find cards that are legal in a given
+// format.
+//
+// You can also find cards that are explicitly banned in a format with the
+// banned: keyword and restricted with the restricted: keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegalityQuery {
+    pub legality: Legality,
+    pub format: Format,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Legality {
+    // Restricted cards are still legal, just limited to a single copy.
+    Legal,
+    Banned,
+    Restricted,
+}
+
+impl Legality {
+    pub fn describe(&self) -> &str {
+        match self {
+            Legality::Legal => "legal",
+            Legality::Banned => "banned",
+            Legality::Restricted => "restricted",
+        }
+    }
+}
+
+impl Display for Legality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Legality::Legal => write!(f, "f"),
+            Legality::Banned => write!(f, "banned"),
+            Legality::Restricted => write!(f, "restricted"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Format {
+    Standard,
+    Future,
+    Historic,
+    Timeless,
+    Gladiator,
+    Pioneer,
+    Explorer,
+    Modern,
+    Legacy,
+    Pauper,
+    Vintage,
+    Penny,
+    Commander,
+    Oathbreaker,
+    StandardBrawl,
+    Brawl,
+    Alchemy,
+    PauperCommander,
+    Duel,
+    OldSchool,
+    Premodern,
+    Predh,
+}
+
+impl Format {
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        let format = match keyword.to_lowercase().as_str() {
+            "standard" => Format::Standard,
+            "future" => Format::Future,
+            "historic" => Format::Historic,
+            "timeless" => Format::Timeless,
+            "gladiator" => Format::Gladiator,
+            "pioneer" => Format::Pioneer,
+            "explorer" => Format::Explorer,
+            "modern" => Format::Modern,
+            "legacy" => Format::Legacy,
+            "pauper" => Format::Pauper,
+            "vintage" => Format::Vintage,
+            "penny" => Format::Penny,
+            "commander" => Format::Commander,
+            "oathbreaker" => Format::Oathbreaker,
+            "standardbrawl" => Format::StandardBrawl,
+            "brawl" => Format::Brawl,
+            "alchemy" => Format::Alchemy,
+            "paupercommander" => Format::PauperCommander,
+            "duel" => Format::Duel,
+            "oldschool" => Format::OldSchool,
+            "premodern" => Format::Premodern,
+            "predh" => Format::Predh,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    pub fn describe(&self) -> &str {
+        match self {
+            Format::Standard => "Standard",
+            Format::Future => "Future Standard",
+            Format::Historic => "Historic",
+            Format::Timeless => "Timeless",
+            Format::Gladiator => "Gladiator",
+            Format::Pioneer => "Pioneer",
+            Format::Explorer => "Explorer",
+            Format::Modern => "Modern",
+            Format::Legacy => "Legacy",
+            Format::Pauper => "Pauper",
+            Format::Vintage => "Vintage",
+            Format::Penny => "Penny Dreadful",
+            Format::Commander => "Commander",
+            Format::Oathbreaker => "Oathbreaker",
+            Format::StandardBrawl => "Standard Brawl",
+            Format::Brawl => "Brawl",
+            Format::Alchemy => "Alchemy",
+            Format::PauperCommander => "Pauper Commander",
+            Format::Duel => "Duel Commander",
+            Format::OldSchool => "Old School 93/94",
+            Format::Premodern => "Premodern",
+            Format::Predh => "PreDH",
+        }
+    }
+}
+
+// The keyword is the same as the key Scryfall uses in a card's legalities.
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let keyword = match self {
+            Format::Standard => "standard",
+            Format::Future => "future",
+            Format::Historic => "historic",
+            Format::Timeless => "timeless",
+            Format::Gladiator => "gladiator",
+            Format::Pioneer => "pioneer",
+            Format::Explorer => "explorer",
+            Format::Modern => "modern",
+            Format::Legacy => "legacy",
+            Format::Pauper => "pauper",
+            Format::Vintage => "vintage",
+            Format::Penny => "penny",
+            Format::Commander => "commander",
+            Format::Oathbreaker => "oathbreaker",
+            Format::StandardBrawl => "standardbrawl",
+            Format::Brawl => "brawl",
+            Format::Alchemy => "alchemy",
+            Format::PauperCommander => "paupercommander",
+            Format::Duel => "duel",
+            Format::OldSchool => "oldschool",
+            Format::Premodern => "premodern",
+            Format::Predh => "predh",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+fn format(input: &str) -> IResult<&str, Format, ErrorTree<&str>> {
+    map_opt(alpha1, Format::from_keyword).parse(input)
+}
+
+fn legality(input: &str) -> IResult<&str, Legality, ErrorTree<&str>> {
+    alt((
+        tag_no_case("format:").value(Legality::Legal),
+        tag_no_case("f:").value(Legality::Legal),
+        tag_no_case("banned:").value(Legality::Banned),
+        tag_no_case("restricted:").value(Legality::Restricted),
+    ))
+    .parse(input)
+}
+
+pub fn legality_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((legality, format))
+        .map(|(legality, format)| LegalityQuery { legality, format })
+        .map(ParsedSearch::legality_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn legality_query(legality_query: LegalityQuery) -> Self {
+        Self::Keyword(SearchKeyword::LegalityQuery(legality_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl LegalityQuery {
+        pub fn new(legality: Legality, format: Format) -> Self {
+            Self { legality, format }
+        }
+    }
+
+    #[test]
+    fn test_f_pauper() {
+        let (_, actual) = legality_query("f:pauper").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::legality_query(LegalityQuery::new(Legality::Legal, Format::Pauper))
+        );
+    }
+
+    #[test]
+    fn test_format_commander() {
+        let (_, actual) = legality_query("format:Commander").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::legality_query(LegalityQuery::new(Legality::Legal, Format::Commander))
+        );
+    }
+
+    #[test]
+    fn test_banned_legacy() {
+        let (_, actual) = legality_query("banned:legacy").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::legality_query(LegalityQuery::new(Legality::Banned, Format::Legacy))
+        );
+    }
+
+    #[test]
+    fn test_restricted_vintage() {
+        let (_, actual) = legality_query("restricted:vintage").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::legality_query(LegalityQuery::new(Legality::Restricted, Format::Vintage))
+        );
+    }
+
+    #[test]
+    fn test_standardbrawl_is_not_standard() {
+        let (_, actual) = legality_query("f:standardbrawl").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::legality_query(LegalityQuery::new(
+                Legality::Legal,
+                Format::StandardBrawl
+            ))
+        );
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        assert!(legality_query("f:notaformat").is_err());
+    }
+}
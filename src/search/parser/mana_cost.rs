@@ -0,0 +1,396 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use nom::{
+    branch::alt,
+    bytes::complete::take_until,
+    character::complete::{digit1, one_of},
+    combinator::map_opt,
+    multi::many1,
+    sequence::delimited,
+    IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag};
+
+use super::numeric_operator::NumericOperator;
+
+// The colors in WUBRG order. Hybrid symbols are stored with their colors in
+// this order so that {G/W} and {W/G} are the same symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ManaColor {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl ManaColor {
+    pub fn from_letter(letter: &str) -> Option<Self> {
+        match letter {
+            "W" => Some(Self::White),
+            "U" => Some(Self::Blue),
+            "B" => Some(Self::Black),
+            "R" => Some(Self::Red),
+            "G" => Some(Self::Green),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ManaColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::White => write!(f, "W"),
+            Self::Blue => write!(f, "U"),
+            Self::Black => write!(f, "B"),
+            Self::Red => write!(f, "R"),
+            Self::Green => write!(f, "G"),
+        }
+    }
+}
+
+// A single symbol from a mana cost using the text version from the
+// Comprehensive Rules. Generic mana is counted rather than stored as a number
+// so {3} is three `Generic` symbols.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ManaSymbol {
+    Variable(char),
+    Generic,
+    Colored(ManaColor),
+    Colorless,
+    Snow,
+    Hybrid(ManaColor, ManaColor),
+    GenericHybrid(u32, ManaColor),
+    Phyrexian(ManaColor),
+    PhyrexianHybrid(ManaColor, ManaColor),
+    // Anything else that shows up in a cost, e.g. {HW} or {∞}.
+    Other(String),
+}
+
+impl ManaSymbol {
+    pub fn is_hybrid(&self) -> bool {
+        matches!(
+            self,
+            Self::Hybrid(_, _) | Self::GenericHybrid(_, _) | Self::PhyrexianHybrid(_, _)
+        )
+    }
+
+    pub fn is_phyrexian(&self) -> bool {
+        matches!(self, Self::Phyrexian(_) | Self::PhyrexianHybrid(_, _))
+    }
+
+    // The colors a symbol counts towards for devotion.
+    pub fn colors(&self) -> Vec<ManaColor> {
+        match self {
+            Self::Colored(color) | Self::GenericHybrid(_, color) | Self::Phyrexian(color) => {
+                vec![*color]
+            }
+            Self::Hybrid(first, second) | Self::PhyrexianHybrid(first, second) => {
+                vec![*first, *second]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn hybrid(first: ManaColor, second: ManaColor) -> (ManaColor, ManaColor) {
+        (first.min(second), first.max(second))
+    }
+
+    // Parses what's inside of the braces of a symbol, returning the symbol and
+    // how many times it counts.
+    fn from_braces(inner: &str) -> Option<(Self, u32)> {
+        let inner = inner.to_uppercase();
+        let parts = inner.split('/').collect::<Vec<_>>();
+        let symbol = match parts.as_slice() {
+            [number] if number.chars().all(|c| c.is_ascii_digit()) => {
+                return number.parse().ok().map(|count| (Self::Generic, count));
+            }
+            ["C"] => Self::Colorless,
+            ["S"] => Self::Snow,
+            [variable @ ("X" | "Y" | "Z")] => Self::Variable(variable.chars().next()?),
+            [color] => Self::Colored(ManaColor::from_letter(color)?),
+            [color, "P"] => Self::Phyrexian(ManaColor::from_letter(color)?),
+            [number, color] if number.chars().all(|c| c.is_ascii_digit()) => {
+                Self::GenericHybrid(number.parse().ok()?, ManaColor::from_letter(color)?)
+            }
+            [first, second] => {
+                let (first, second) = Self::hybrid(
+                    ManaColor::from_letter(first)?,
+                    ManaColor::from_letter(second)?,
+                );
+                Self::Hybrid(first, second)
+            }
+            [first, second, "P"] => {
+                let (first, second) = Self::hybrid(
+                    ManaColor::from_letter(first)?,
+                    ManaColor::from_letter(second)?,
+                );
+                Self::PhyrexianHybrid(first, second)
+            }
+            _ => return None,
+        };
+        Some((symbol, 1))
+    }
+}
+
+impl Display for ManaSymbol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Variable(variable) => write!(f, "{{{}}}", variable),
+            Self::Generic => write!(f, "{{1}}"),
+            Self::Colored(color) => write!(f, "{{{}}}", color),
+            Self::Colorless => write!(f, "{{C}}"),
+            Self::Snow => write!(f, "{{S}}"),
+            Self::Hybrid(first, second) => write!(f, "{{{}/{}}}", first, second),
+            Self::GenericHybrid(number, color) => write!(f, "{{{}/{}}}", number, color),
+            Self::Phyrexian(color) => write!(f, "{{{}/P}}", color),
+            Self::PhyrexianHybrid(first, second) => write!(f, "{{{}/{}/P}}", first, second),
+            Self::Other(other) => write!(f, "{{{}}}", other),
+        }
+    }
+}
+
+// The kinds of symbols that can be looked for with is:hybrid and is:phyrexian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManaSymbolKind {
+    Hybrid,
+    Phyrexian,
+}
+
+impl ManaSymbolKind {
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "hybrid" => Some(Self::Hybrid),
+            "phyrexian" => Some(Self::Phyrexian),
+            _ => None,
+        }
+    }
+
+    pub fn matches(&self, mana_cost: &ManaCost) -> bool {
+        match self {
+            Self::Hybrid => mana_cost.has_hybrid(),
+            Self::Phyrexian => mana_cost.has_phyrexian(),
+        }
+    }
+}
+
+impl Display for ManaSymbolKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hybrid => write!(f, "hybrid"),
+            Self::Phyrexian => write!(f, "phyrexian"),
+        }
+    }
+}
+
+// A mana cost as a multiset of symbols. One cost is greater than another if it
+// includes all of the same symbols and more, and less if it only includes a
+// subset of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManaCost {
+    symbols: BTreeMap<ManaSymbol, u32>,
+}
+
+impl ManaCost {
+    fn add(mut self, (symbol, count): (ManaSymbol, u32)) -> Self {
+        if count > 0 {
+            // Generic mana can be typed with any number of digits, so the count
+            // stops at the largest u32 instead of overflowing.
+            let total = self.symbols.entry(symbol).or_default();
+            *total = total.saturating_add(count);
+        }
+        self
+    }
+
+    // Reads the mana cost stored on a card, e.g. `{2}{W}{W}` or, for split
+    // cards, `{R} // {U}`. This is more lenient than the search syntax since
+    // it has to handle every symbol that's been printed.
+    pub fn from_card(mana_cost: &str) -> Self {
+        mana_cost
+            .split('{')
+            .skip(1)
+            .filter_map(|symbol| symbol.split_once('}'))
+            .map(|(inner, _)| {
+                ManaSymbol::from_braces(inner)
+                    .unwrap_or_else(|| (ManaSymbol::Other(inner.to_string()), 1))
+            })
+            .fold(Self::default(), Self::add)
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = (&ManaSymbol, u32)> {
+        self.symbols.iter().map(|(symbol, count)| (symbol, *count))
+    }
+
+    fn count(&self, symbol: &ManaSymbol) -> u32 {
+        self.symbols.get(symbol).copied().unwrap_or_default()
+    }
+
+    pub fn contains(&self, other: &ManaCost) -> bool {
+        other
+            .symbols()
+            .all(|(symbol, count)| self.count(symbol) >= count)
+    }
+
+    pub fn compare(&self, operator: &NumericOperator, other: &ManaCost) -> bool {
+        match operator {
+            NumericOperator::Colon | NumericOperator::GreaterThanOrEqual => self.contains(other),
+            NumericOperator::Equal => self == other,
+            NumericOperator::NotEqual => self != other,
+            NumericOperator::GreaterThan => self.contains(other) && self != other,
+            NumericOperator::LessThan => other.contains(self) && self != other,
+            NumericOperator::LessThanOrEqual => other.contains(self),
+        }
+    }
+
+    // Each symbol that includes any of the colors counts once, so {W/U}
+    // counts towards devotion to white and blue but only adds one.
+    pub fn devotion(&self, colors: &[ManaColor]) -> u32 {
+        self.symbols()
+            .filter(|(symbol, _)| symbol.colors().iter().any(|c| colors.contains(c)))
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn has_hybrid(&self) -> bool {
+        self.symbols.keys().any(ManaSymbol::is_hybrid)
+    }
+
+    pub fn has_phyrexian(&self) -> bool {
+        self.symbols.keys().any(ManaSymbol::is_phyrexian)
+    }
+}
+
+impl Display for ManaCost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (symbol, count) in self.symbols() {
+            match symbol {
+                ManaSymbol::Generic => write!(f, "{{{}}}", count)?,
+                _ => (0..count).try_for_each(|_| write!(f, "{}", symbol))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn braced_symbol(input: &str) -> IResult<&str, (ManaSymbol, u32), ErrorTree<&str>> {
+    map_opt(
+        delimited(tag("{"), take_until("}"), tag("}")),
+        ManaSymbol::from_braces,
+    )
+    .parse(input)
+}
+
+// Shorthand is allowed for symbols that aren't split: G is the same as {G}.
+fn shorthand_symbol(input: &str) -> IResult<&str, (ManaSymbol, u32), ErrorTree<&str>> {
+    alt((
+        map_opt(digit1, ManaSymbol::from_braces),
+        map_opt(one_of("WUBRGCSXYZwubrgcsxyz"), |c: char| {
+            ManaSymbol::from_braces(&c.to_string())
+        }),
+    ))
+    .parse(input)
+}
+
+pub fn mana_cost(input: &str) -> IResult<&str, ManaCost, ErrorTree<&str>> {
+    many1(alt((braced_symbol, shorthand_symbol)))
+        .map(|symbols| symbols.into_iter().fold(ManaCost::default(), ManaCost::add))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cost(input: &str) -> ManaCost {
+        let (rest, actual) = mana_cost(input).unwrap();
+        assert_eq!(rest, "");
+        actual
+    }
+
+    #[test]
+    fn shorthand_is_the_same_as_braces() {
+        assert_eq!(cost("2WW"), cost("{2}{W}{W}"));
+        assert_eq!(cost("2ww"), ManaCost::from_card("{2}{W}{W}"));
+    }
+
+    #[test]
+    fn generic_mana_is_counted() {
+        assert_eq!(cost("{1}{1}{1}"), cost("3"));
+        assert_eq!(cost("12").to_string(), "{12}");
+    }
+
+    #[test]
+    fn huge_generic_mana_does_not_overflow() {
+        assert_eq!(cost("{4294967295}{1}").to_string(), "{4294967295}");
+    }
+
+    #[test]
+    fn hybrid_colors_are_normalized() {
+        assert_eq!(cost("{g/w}"), cost("{W/G}"));
+        assert_eq!(
+            cost("{G/W}").symbols().collect::<Vec<_>>(),
+            vec![(&ManaSymbol::Hybrid(ManaColor::White, ManaColor::Green), 1)]
+        );
+    }
+
+    #[test]
+    fn parses_every_symbol_kind() {
+        let actual = cost("{X}{2/G}{R/P}{G/W/P}{U/B}{C}{S}1W");
+        assert_eq!(actual.to_string(), "{X}{1}{W}{C}{S}{U/B}{2/G}{R/P}{W/G/P}");
+    }
+
+    #[test]
+    fn split_symbols_need_braces() {
+        let (rest, _) = mana_cost("R/P").unwrap();
+        assert_eq!(rest, "/P");
+    }
+
+    #[test]
+    fn unknown_card_symbols_are_kept() {
+        let actual = ManaCost::from_card("{HW}{1}");
+        assert_eq!(actual.to_string(), "{1}{HW}");
+    }
+
+    #[test]
+    fn split_card_costs_are_combined() {
+        assert_eq!(ManaCost::from_card("{1}{R} // {U}"), cost("1RU"));
+    }
+
+    #[test]
+    fn hybrid_and_phyrexian() {
+        assert!(cost("{2/G}").has_hybrid());
+        assert!(!cost("{2/G}").has_phyrexian());
+        assert!(cost("{R/P}").has_phyrexian());
+        assert!(!cost("{R/P}").has_hybrid());
+        assert!(cost("{G/W/P}").has_hybrid());
+        assert!(cost("{G/W/P}").has_phyrexian());
+        assert!(!cost("2WW").has_hybrid());
+    }
+
+    #[test]
+    fn devotion_counts_each_symbol_once() {
+        let card = ManaCost::from_card("{1}{U}{U/B}{B/P}{2/B}");
+        assert_eq!(card.devotion(&[ManaColor::Blue]), 2);
+        assert_eq!(card.devotion(&[ManaColor::Black]), 3);
+        assert_eq!(card.devotion(&[ManaColor::Blue, ManaColor::Black]), 4);
+        assert_eq!(card.devotion(&[ManaColor::Green]), 0);
+    }
+
+    #[test]
+    fn compare_as_multisets() {
+        let card = cost("{3}{W}{U}");
+        assert!(card.compare(&NumericOperator::Colon, &cost("WU")));
+        assert!(card.compare(&NumericOperator::GreaterThan, &cost("2WU")));
+        assert!(!card.compare(&NumericOperator::GreaterThan, &cost("3WU")));
+        assert!(card.compare(&NumericOperator::GreaterThanOrEqual, &cost("3WU")));
+        assert!(card.compare(&NumericOperator::Equal, &cost("3WU")));
+        assert!(!card.compare(&NumericOperator::Equal, &cost("WU")));
+        assert!(card.compare(&NumericOperator::LessThan, &cost("4WU")));
+        assert!(!card.compare(&NumericOperator::LessThan, &cost("4W")));
+        assert!(card.compare(&NumericOperator::LessThanOrEqual, &cost("3WU")));
+        assert!(card.compare(&NumericOperator::NotEqual, &cost("3W")));
+    }
+}
@@ -0,0 +1,92 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{
+    mana_cost::{mana_cost, ManaCost},
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// Use the m: or mana: keyword to search for cards that have certain symbols in
+// their mana costs. A mana cost is greater than another if it includes all the
+// same symbols and more, and it's less if it includes only a subset of symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManaCostQuery {
+    pub operator: NumericOperator,
+    pub operand: ManaCost,
+}
+
+pub fn mana_cost_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((tag_no_case("mana"), tag_no_case("m"))),
+        numeric_operator,
+        mana_cost,
+    ))
+    .map(|(_, operator, operand)| ManaCostQuery { operator, operand })
+    .map(ParsedSearch::mana_cost_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn mana_cost_query(mana_cost_query: ManaCostQuery) -> Self {
+        Self::Keyword(SearchKeyword::ManaCostQuery(mana_cost_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::mana_cost::{ManaColor, ManaSymbol};
+
+    impl ManaCostQuery {
+        pub fn new(operator: NumericOperator, operand: &str) -> Self {
+            let (_, operand) = mana_cost(operand).unwrap();
+            Self { operator, operand }
+        }
+    }
+
+    #[test]
+    fn mana_braces() {
+        let (_, actual) = mana_cost_query("mana:{G}{U}").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_cost_query(ManaCostQuery::new(NumericOperator::Colon, "GU"))
+        );
+    }
+
+    #[test]
+    fn m_shorthand() {
+        let (_, actual) = mana_cost_query("m:2WW").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_cost_query(ManaCostQuery::new(NumericOperator::Colon, "{2}{W}{W}"))
+        );
+    }
+
+    #[test]
+    fn m_greater_than() {
+        let (_, actual) = mana_cost_query("m>3WU").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_cost_query(ManaCostQuery::new(NumericOperator::GreaterThan, "3WU"))
+        );
+    }
+
+    #[test]
+    fn m_phyrexian() {
+        let (_, actual) = mana_cost_query("m:{R/P}").unwrap();
+        let ParsedSearch::Keyword(SearchKeyword::ManaCostQuery(actual)) = actual else {
+            panic!("Expected a mana cost query");
+        };
+        assert_eq!(
+            actual.operand.symbols().collect::<Vec<_>>(),
+            vec![(&ManaSymbol::Phyrexian(ManaColor::Red), 1)]
+        );
+    }
+
+    #[test]
+    fn manavalue_is_not_a_mana_cost() {
+        assert!(mana_cost_query("manavalue:3").is_err());
+    }
+}
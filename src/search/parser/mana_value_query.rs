@@ -0,0 +1,170 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{
+    error::ErrorTree,
+    tag::complete::{tag, tag_no_case},
+    ParserExt,
+};
+
+use super::{
+    numeric_operator::{number, numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// You can find cards of a specific mana value with manavalue or mv, comparing
+// with a numeric expression (>, <, =, >=, <=, and !=). You can also find even
+// or odd mana costs with manavalue:even or manavalue:odd
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManaValueQuery {
+    pub operator: NumericOperator,
+    pub operand: ManaValueOperand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManaValueOperand {
+    Number(String),
+    Even,
+    Odd,
+}
+
+impl ManaValueOperand {
+    pub fn describe(&self) -> String {
+        match self {
+            ManaValueOperand::Number(num) => num.to_string(),
+            ManaValueOperand::Even => "even".to_string(),
+            ManaValueOperand::Odd => "odd".to_string(),
+        }
+    }
+}
+
+fn parity_operator(input: &str) -> IResult<&str, NumericOperator, ErrorTree<&str>> {
+    alt((
+        tag("!=").value(NumericOperator::NotEqual),
+        tag(":").value(NumericOperator::Colon),
+        tag("=").value(NumericOperator::Equal),
+    ))
+    .parse(input)
+}
+
+fn parity(input: &str) -> IResult<&str, ManaValueOperand, ErrorTree<&str>> {
+    alt((
+        tag_no_case("even").value(ManaValueOperand::Even),
+        tag_no_case("odd").value(ManaValueOperand::Odd),
+    ))
+    .parse(input)
+}
+
+fn mana_value_keyword(input: &str) -> IResult<&str, &str, ErrorTree<&str>> {
+    alt((
+        tag_no_case("manavalue"),
+        tag_no_case("mv"),
+        tag_no_case("cmc"),
+    ))
+    .parse(input)
+}
+
+pub fn mana_value_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        mana_value_keyword,
+        alt((
+            tuple((parity_operator, parity)),
+            tuple((
+                numeric_operator,
+                number.map(|num| ManaValueOperand::Number(num.to_string())),
+            )),
+        )),
+    ))
+    .map(|(_, (operator, operand))| ManaValueQuery { operator, operand })
+    .map(ParsedSearch::mana_value_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn mana_value_query(mana_value_query: ManaValueQuery) -> Self {
+        Self::Keyword(SearchKeyword::ManaValueQuery(mana_value_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl ManaValueQuery {
+        pub fn new(operator: NumericOperator, operand: ManaValueOperand) -> Self {
+            Self { operator, operand }
+        }
+    }
+
+    #[test]
+    fn test_mv_equals_5() {
+        let (_, actual) = mana_value_query("mv=5").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_value_query(ManaValueQuery::new(
+                NumericOperator::Equal,
+                ManaValueOperand::Number("5".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cmc_less_than_or_equal_2() {
+        let (_, actual) = mana_value_query("cmc<=2").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_value_query(ManaValueQuery::new(
+                NumericOperator::LessThanOrEqual,
+                ManaValueOperand::Number("2".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_manavalue_not_equal_half() {
+        let (_, actual) = mana_value_query("manavalue!=0.5").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_value_query(ManaValueQuery::new(
+                NumericOperator::NotEqual,
+                ManaValueOperand::Number("0.5".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_manavalue_even() {
+        let (_, actual) = mana_value_query("manavalue:even").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_value_query(ManaValueQuery::new(
+                NumericOperator::Colon,
+                ManaValueOperand::Even
+            ))
+        );
+    }
+
+    #[test]
+    fn test_mv_odd() {
+        let (_, actual) = mana_value_query("mv:ODD").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::mana_value_query(ManaValueQuery::new(
+                NumericOperator::Colon,
+                ManaValueOperand::Odd
+            ))
+        );
+    }
+
+    #[test]
+    fn test_mv_must_be_a_plain_number() {
+        assert!(mana_value_query("mv>inf").is_err());
+        assert!(mana_value_query("mv=nan").is_err());
+        let (rest, _) = mana_value_query("mv<1e999").unwrap();
+        assert_eq!(rest, "e999");
+    }
+
+    #[test]
+    fn test_mv_greater_than_even_is_not_allowed() {
+        assert!(mana_value_query("mv>even").is_err());
+    }
+}
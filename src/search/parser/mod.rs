@@ -1,12 +1,40 @@
+pub(crate) mod artist_query;
+pub(crate) mod collector_number_query;
 pub(crate) mod color;
+pub(crate) mod color_count_query;
 pub(crate) mod color_identity_query;
 pub(crate) mod color_query;
+pub(crate) mod date_query;
+pub(crate) mod devotion_query;
+pub(crate) mod flavor_query;
+pub(crate) mod frame_query;
+pub(crate) mod game_query;
+pub(crate) mod id_query;
+pub(crate) mod in_query;
+pub(crate) mod include_query;
+pub(crate) mod indicator_query;
+pub(crate) mod is_query;
 pub(crate) mod keyword;
+pub(crate) mod language_query;
+pub(crate) mod legality_query;
+pub(crate) mod mana_cost;
+pub(crate) mod mana_cost_query;
+pub(crate) mod mana_value_query;
 pub(crate) mod name;
+pub(crate) mod numeric_operator;
 pub(crate) mod oracle_query;
+pub(crate) mod order_query;
 pub(crate) mod parsed_search;
-pub(crate) mod power_query;
+pub(crate) mod price_query;
+pub(crate) mod prints_query;
+pub(crate) mod produces_query;
+pub(crate) mod rarity_query;
+pub(crate) mod set_query;
+pub(crate) mod shortcut;
+pub(crate) mod stat_query;
 pub(crate) mod type_line_query;
+pub(crate) mod unique_query;
+pub(crate) mod watermark_query;
 
 use nom_supreme::{error::ErrorTree, final_parser::final_parser};
 
@@ -14,7 +42,7 @@ pub use self::{
     color_query::{ColorOperator, *},
     name::{Name, *},
     parsed_search::{ParsedSearch, *},
-    power_query::{PowerOperator, *},
+    stat_query::{StatQuery, *},
 };
 
 pub fn search(input: &str) -> Result<ParsedSearch, ErrorTree<&str>> {
@@ -1,17 +1,50 @@
 use nom::{
     branch::alt,
-    bytes::complete::{tag_no_case, take_while},
-    sequence::delimited,
+    bytes::complete::{is_not, tag_no_case, take_while},
+    character::complete::anychar,
+    combinator::recognize,
+    multi::fold_many0,
+    sequence::{delimited, preceded, tuple},
     IResult, Parser,
 };
 use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use regex::Regex;
 
 use super::ParsedSearch;
 use crate::search::SearchKeyword;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct Name {
-    pub text: String,
+pub enum Name {
+    /// Matches any card whose name contains the text or matches the regex.
+    Contains(TextOperand),
+    /// Matches any card whose name, or the name of one of its faces, is
+    /// exactly the text. Written as `!fire` or `!"Sift Through Sands"`.
+    Exact(String),
+}
+
+/// The operand for keywords that match against a card's text. Either a plain
+/// substring or a `/regular expression/`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TextOperand {
+    Text(String),
+    Regex(String),
+}
+
+impl TextOperand {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            TextOperand::Text(text) | TextOperand::Regex(text) => text.is_empty(),
+        }
+    }
+}
+
+impl std::fmt::Display for TextOperand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            TextOperand::Text(text) => write!(f, "{}", text),
+            TextOperand::Regex(regex) => write!(f, "/{}/", regex.replace('/', r"\/")),
+        }
+    }
 }
 
 pub fn quoted_or_until_space(input: &str) -> IResult<&str, &str, ErrorTree<&str>> {
@@ -24,20 +57,63 @@ pub fn quoted_or_until_space(input: &str) -> IResult<&str, &str, ErrorTree<&str>
     .parse(input)
 }
 
+// Parses a `/regex/`. Forward slashes inside of the regex must be escaped as
+// `\/`, any other escapes are left as-is for the regex engine. Invalid regexes
+// fail to parse so they never make it to the database.
+pub fn regex(input: &str) -> IResult<&str, String, ErrorTree<&str>> {
+    delimited(
+        tag("/"),
+        fold_many0(
+            alt((
+                tag(r"\/").value("/"),
+                recognize(tuple((tag(r"\"), anychar))),
+                is_not(r"/\"),
+            )),
+            String::new,
+            |mut acc, part| {
+                acc.push_str(part);
+                acc
+            },
+        ),
+        tag("/"),
+    )
+    .map_res_cut(|pattern| Regex::new(&pattern).map(|_| pattern))
+    .parse(input)
+}
+
+pub fn text_operand(input: &str) -> IResult<&str, TextOperand, ErrorTree<&str>> {
+    alt((
+        regex.map(TextOperand::Regex),
+        quoted_or_until_space.map(|text| TextOperand::Text(text.to_string())),
+    ))
+    .parse(input)
+}
+
 pub fn name(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
     // TODO - Not sure if this is necessary anymore.
     alt((tag_no_case("or"), tag_no_case("and")))
         .not()
         .peek()
         .parse(input)?;
-    quoted_or_until_space.map(ParsedSearch::name).parse(input)
+    alt((
+        preceded(tag("!"), quoted_or_until_space).map(ParsedSearch::exact_name),
+        preceded(tag_no_case("name:"), text_operand).map(ParsedSearch::name_operand),
+        quoted_or_until_space.map(ParsedSearch::name),
+    ))
+    .parse(input)
 }
 
 impl ParsedSearch {
     pub fn name(name: &str) -> Self {
-        Self::Keyword(SearchKeyword::Name(Name {
-            text: name.to_string(),
-        }))
+        Self::name_operand(TextOperand::Text(name.to_string()))
+    }
+
+    pub fn name_operand(text: TextOperand) -> Self {
+        Self::Keyword(SearchKeyword::Name(Name::Contains(text)))
+    }
+
+    pub fn exact_name(name: &str) -> Self {
+        Self::Keyword(SearchKeyword::Name(Name::Exact(name.to_string())))
     }
 }
 
@@ -68,4 +144,54 @@ mod tests {
         let (_, actual) = name("name").unwrap();
         assert_eq!(actual, ParsedSearch::name("name"));
     }
+
+    #[test]
+    fn test_parse_name_keyword() {
+        let (_, actual) = name(r#"name:"Sift Through""#).unwrap();
+        assert_eq!(actual, ParsedSearch::name("Sift Through"));
+    }
+
+    #[test]
+    fn test_parse_name_regex() {
+        let (_, actual) = name(r"name:/^fire\b/").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::name_operand(TextOperand::Regex(r"^fire\b".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_exact_name() {
+        let (_, actual) = name("!fire").unwrap();
+        assert_eq!(actual, ParsedSearch::exact_name("fire"));
+    }
+
+    #[test]
+    fn test_parse_exact_name_quoted() {
+        let (_, actual) = name(r#"!"Sift Through Sands""#).unwrap();
+        assert_eq!(actual, ParsedSearch::exact_name("Sift Through Sands"));
+    }
+
+    #[test]
+    fn regex_with_escaped_slash() {
+        let (rest, actual) = regex(r"/fire \/\/ ice/ t:instant").unwrap();
+        assert_eq!(rest, " t:instant");
+        assert_eq!(actual, "fire // ice");
+    }
+
+    #[test]
+    fn regex_with_spaces_and_parens() {
+        let (_, actual) = regex(r"/\(\{t\}: add .*\)/").unwrap();
+        assert_eq!(actual, r"\(\{t\}: add .*\)");
+    }
+
+    #[test]
+    fn invalid_regex_does_not_parse() {
+        assert!(regex("/[a-/").is_err());
+    }
+
+    #[test]
+    fn unterminated_regex_does_not_parse() {
+        assert!(regex("/fire").is_err());
+    }
 }
@@ -0,0 +1,139 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1},
+    combinator::recognize,
+    sequence::tuple,
+    IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+
+// The comparison operators shared by the numeric keywords (mv, cmc, etc.). A
+// colon means the same thing as an equals sign for all of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NumericOperator {
+    LessThan,
+    LessThanOrEqual,
+    NotEqual,
+    Colon,
+    Equal,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl NumericOperator {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::LessThan => "less than",
+            Self::LessThanOrEqual => "less than or equal to",
+            Self::NotEqual => "not equal to",
+            Self::Equal | Self::Colon => "equal to",
+            Self::GreaterThan => "greater than",
+            Self::GreaterThanOrEqual => "greater than or equal to",
+        }
+    }
+
+    // Applies the operator in Rust for operands that have an ordering but
+    // aren't stored as numbers, such as rarities.
+    pub fn compare<T: Ord>(&self, left: &T, right: &T) -> bool {
+        match self {
+            Self::LessThan => left < right,
+            Self::LessThanOrEqual => left <= right,
+            Self::NotEqual => left != right,
+            Self::Colon | Self::Equal => left == right,
+            Self::GreaterThan => left > right,
+            Self::GreaterThanOrEqual => left >= right,
+        }
+    }
+
+    pub fn as_sql(&self) -> &str {
+        match self {
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::NotEqual => "!=",
+            Self::Colon | Self::Equal => "=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+        }
+    }
+}
+
+impl Display for NumericOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LessThan => write!(f, "<"),
+            Self::LessThanOrEqual => write!(f, "<="),
+            Self::NotEqual => write!(f, "!="),
+            Self::Colon => write!(f, ":"),
+            Self::Equal => write!(f, "="),
+            Self::GreaterThan => write!(f, ">"),
+            Self::GreaterThanOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+pub fn numeric_operator(input: &str) -> IResult<&str, NumericOperator, ErrorTree<&str>> {
+    alt((
+        tag("!=").value(NumericOperator::NotEqual),
+        tag("<=").value(NumericOperator::LessThanOrEqual),
+        tag(">=").value(NumericOperator::GreaterThanOrEqual),
+        tag("<").value(NumericOperator::LessThan),
+        tag(":").value(NumericOperator::Colon),
+        tag("=").value(NumericOperator::Equal),
+        tag(">").value(NumericOperator::GreaterThan),
+    ))
+    .parse(input)
+}
+
+// The number compared against by the numeric keywords. It's kept as it was
+// typed since it ends up in the SQL, so only plain decimal numbers are allowed
+// rather than anything that parses as a float (inf, nan, 1e999, etc).
+pub fn number(input: &str) -> IResult<&str, &str, ErrorTree<&str>> {
+    recognize(tuple((
+        char('-').opt(),
+        digit1,
+        tuple((char('.'), digit1)).opt(),
+    )))
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_character_operators_win() {
+        let (rest, actual) = numeric_operator(">=3").unwrap();
+        assert_eq!(actual, NumericOperator::GreaterThanOrEqual);
+        assert_eq!(rest, "3");
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number("-1.5 rest").unwrap(), (" rest", "-1.5"));
+        assert_eq!(number("3.").unwrap(), (".", "3"));
+    }
+
+    #[test]
+    fn test_number_is_not_any_float() {
+        assert!(number("inf").is_err());
+        assert!(number("nan").is_err());
+        assert!(number(".5").is_err());
+    }
+
+    #[test]
+    fn test_colon_is_equal_in_sql() {
+        assert_eq!(
+            NumericOperator::Colon.as_sql(),
+            NumericOperator::Equal.as_sql()
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        assert!(NumericOperator::GreaterThanOrEqual.compare(&3, &3));
+        assert!(NumericOperator::LessThan.compare(&2, &3));
+        assert!(!NumericOperator::Colon.compare(&2, &3));
+    }
+}
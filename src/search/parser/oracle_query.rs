@@ -1,20 +1,33 @@
 use nom::{branch::alt, sequence::tuple, IResult, Parser};
-use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
 
-use super::{name::quoted_or_until_space, parsed_search::SearchKeyword, ParsedSearch};
+use super::{
+    name::{text_operand, TextOperand},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
 
+// o: searches a card's rules text without its reminder text, and fo: searches
+// all of it. ~ or CARDNAME in the text stands for the card's own name.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OracleQuery {
-    pub oracle_text: String,
+    pub oracle_text: TextOperand,
+    pub full_text: bool,
 }
 
 pub fn oracle_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
     tuple((
-        alt((tag_no_case("o:"), tag_no_case("oracle:"))),
-        quoted_or_until_space,
+        alt((
+            tag_no_case("o:").value(false),
+            tag_no_case("oracle:").value(false),
+            tag_no_case("fo:").value(true),
+            tag_no_case("fulloracle:").value(true),
+        )),
+        text_operand,
     ))
-    .map(|(_, oracle_text)| OracleQuery {
-        oracle_text: oracle_text.to_string(),
+    .map(|(full_text, oracle_text)| OracleQuery {
+        oracle_text,
+        full_text,
     })
     .map(ParsedSearch::oracle_query)
     .parse(input)
@@ -36,7 +49,44 @@ mod tests {
         assert_eq!(
             actual,
             ParsedSearch::oracle_query(OracleQuery {
-                oracle_text: "Double Strike".to_string()
+                oracle_text: TextOperand::Text("Double Strike".to_string()),
+                full_text: false,
+            })
+        );
+    }
+
+    #[test]
+    fn oracle_text_regex() {
+        let (_, actual) = oracle_query(r"o:/^\{T\}: Add/").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::oracle_query(OracleQuery {
+                oracle_text: TextOperand::Regex(r"^\{T\}: Add".to_string()),
+                full_text: false,
+            })
+        );
+    }
+
+    #[test]
+    fn full_oracle_text() {
+        let (_, actual) = oracle_query(r#"fo:"can't be countered""#).unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::oracle_query(OracleQuery {
+                oracle_text: TextOperand::Text("can't be countered".to_string()),
+                full_text: true,
+            })
+        );
+    }
+
+    #[test]
+    fn card_name_placeholder() {
+        let (_, actual) = oracle_query(r#"o:"~ enters the battlefield tapped""#).unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::oracle_query(OracleQuery {
+                oracle_text: TextOperand::Text("~ enters the battlefield tapped".to_string()),
+                full_text: false,
             })
         );
     }
@@ -0,0 +1,160 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Name,
+    Cmc,
+    Power,
+    Toughness,
+    Released,
+    Rarity,
+    Color,
+    Edhrec,
+    Usd,
+    Set,
+}
+
+impl SortOrder {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Name => "name",
+            Self::Cmc => "mana value",
+            Self::Power => "power",
+            Self::Toughness => "toughness",
+            Self::Released => "release date",
+            Self::Rarity => "rarity",
+            Self::Color => "color",
+            Self::Edhrec => "EDHREC rank",
+            Self::Usd => "USD price",
+            Self::Set => "set and collector number",
+        }
+    }
+}
+
+impl Display for SortOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name => write!(f, "name"),
+            Self::Cmc => write!(f, "cmc"),
+            Self::Power => write!(f, "power"),
+            Self::Toughness => write!(f, "toughness"),
+            Self::Released => write!(f, "released"),
+            Self::Rarity => write!(f, "rarity"),
+            Self::Color => write!(f, "color"),
+            Self::Edhrec => write!(f, "edhrec"),
+            Self::Usd => write!(f, "usd"),
+            Self::Set => write!(f, "set"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Asc => write!(f, "asc"),
+            Self::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+// order: changes how results are sorted rather than which cards are found.
+// Results are sorted by name unless the search says otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderQuery {
+    pub order: SortOrder,
+}
+
+// direction:asc or direction:desc chooses which way results are sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionQuery {
+    pub direction: Direction,
+}
+
+fn sort_order(input: &str) -> IResult<&str, SortOrder, ErrorTree<&str>> {
+    alt((
+        tag_no_case("name").value(SortOrder::Name),
+        tag_no_case("cmc").value(SortOrder::Cmc),
+        tag_no_case("power").value(SortOrder::Power),
+        tag_no_case("toughness").value(SortOrder::Toughness),
+        tag_no_case("released").value(SortOrder::Released),
+        tag_no_case("rarity").value(SortOrder::Rarity),
+        tag_no_case("color").value(SortOrder::Color),
+        tag_no_case("edhrec").value(SortOrder::Edhrec),
+        tag_no_case("usd").value(SortOrder::Usd),
+        tag_no_case("set").value(SortOrder::Set),
+    ))
+    .parse(input)
+}
+
+pub fn order_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("order:"), sort_order))
+        .map(|(_, order)| OrderQuery { order })
+        .map(ParsedSearch::order_query)
+        .parse(input)
+}
+
+pub fn direction_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        tag_no_case("direction:"),
+        alt((
+            tag_no_case("asc").value(Direction::Asc),
+            tag_no_case("desc").value(Direction::Desc),
+        )),
+    ))
+    .map(|(_, direction)| DirectionQuery { direction })
+    .map(ParsedSearch::direction_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn order_query(order_query: OrderQuery) -> Self {
+        Self::Keyword(SearchKeyword::OrderQuery(order_query))
+    }
+    pub fn direction_query(direction_query: DirectionQuery) -> Self {
+        Self::Keyword(SearchKeyword::DirectionQuery(direction_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_edhrec() {
+        let (_, actual) = order_query("order:EDHREC").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::order_query(OrderQuery {
+                order: SortOrder::Edhrec
+            })
+        );
+    }
+
+    #[test]
+    fn direction_desc() {
+        let (_, actual) = direction_query("direction:desc").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::direction_query(DirectionQuery {
+                direction: Direction::Desc
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_order_does_not_parse() {
+        assert!(order_query("order:artist").is_err());
+    }
+}
@@ -9,25 +9,84 @@ use nom::{
 use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
 
 use super::{
+    artist_query::{artist_query, artists_query, ArtistQuery, ArtistsQuery},
+    collector_number_query::{collector_number_query, CollectorNumberQuery},
+    color_count_query::{color_count_query, ColorCountQuery},
     color_identity_query::{color_identity_query, ColorIdentityQuery},
+    date_query::{date_query, year_query, DateQuery, YearQuery},
+    devotion_query::{devotion_query, DevotionQuery},
+    flavor_query::{flavor_query, FlavorQuery},
+    frame_query::{border_query, frame_query, stamp_query, BorderQuery, FrameQuery, StampQuery},
+    game_query::{game_query, GameQuery},
+    id_query::{id_query, IdQuery},
+    in_query::{in_query, InQuery},
+    include_query::{include_query, IncludeQuery},
+    indicator_query::{indicator_query, IndicatorQuery},
+    is_query::{is_query, IsQuery},
     keyword::{keyword_query, KeywordQuery},
+    language_query::{language_query, LanguageQuery},
+    legality_query::{legality_query, LegalityQuery},
+    mana_cost_query::{mana_cost_query, ManaCostQuery},
+    mana_value_query::{mana_value_query, ManaValueQuery},
     name::Name,
     oracle_query::{oracle_query, OracleQuery},
+    order_query::{direction_query, order_query, DirectionQuery, OrderQuery},
+    price_query::{cheapest_query, price_query, CheapestQuery, PriceQuery},
+    prints_query::{prints_query, PrintsQuery},
+    produces_query::{produces_query, ProducesQuery},
+    rarity_query::{rarity_query, RarityQuery},
+    set_query::{set_query, set_type_query, SetQuery, SetTypeQuery},
+    shortcut::{shortcut_query, ShortcutQuery},
     type_line_query::TypeLineQuery,
+    unique_query::{unique_query, UniqueQuery},
+    watermark_query::{watermark_query, WatermarkQuery},
 };
 use crate::search::{
-    color_query, name, power_query, type_line_query::type_line_query, ColorQuery, PowerQuery,
+    color_query, name, stat_query, type_line_query::type_line_query, ColorQuery, StatQuery,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum SearchKeyword {
     ColorQuery(ColorQuery),
     ColorIdentityQuery(ColorIdentityQuery),
-    PowerQuery(PowerQuery),
+    StatQuery(StatQuery),
     OracleQuery(OracleQuery),
     Name(Name),
     TypeLineQuery(TypeLineQuery),
     Keyword(KeywordQuery),
+    ManaValueQuery(ManaValueQuery),
+    LegalityQuery(LegalityQuery),
+    SetQuery(SetQuery),
+    SetTypeQuery(SetTypeQuery),
+    CollectorNumberQuery(CollectorNumberQuery),
+    RarityQuery(RarityQuery),
+    ManaCostQuery(ManaCostQuery),
+    ProducesQuery(ProducesQuery),
+    DevotionQuery(DevotionQuery),
+    ArtistQuery(ArtistQuery),
+    ArtistsQuery(ArtistsQuery),
+    FlavorQuery(FlavorQuery),
+    WatermarkQuery(WatermarkQuery),
+    IsQuery(IsQuery),
+    YearQuery(YearQuery),
+    DateQuery(DateQuery),
+    PriceQuery(PriceQuery),
+    CheapestQuery(CheapestQuery),
+    LanguageQuery(LanguageQuery),
+    InQuery(InQuery),
+    PrintsQuery(PrintsQuery),
+    GameQuery(GameQuery),
+    IdQuery(IdQuery),
+    BorderQuery(BorderQuery),
+    FrameQuery(FrameQuery),
+    StampQuery(StampQuery),
+    ShortcutQuery(ShortcutQuery),
+    IncludeQuery(IncludeQuery),
+    OrderQuery(OrderQuery),
+    DirectionQuery(DirectionQuery),
+    UniqueQuery(UniqueQuery),
+    ColorCountQuery(ColorCountQuery),
+    IndicatorQuery(IndicatorQuery),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -38,6 +97,54 @@ pub enum ParsedSearch {
     Keyword(SearchKeyword),
 }
 
+impl ParsedSearch {
+    // Checks every keyword in the search, however deeply it's nested, for one
+    // that matches. This is how keywords that change the defaults for the whole
+    // search are found. Shortcuts are checked along with what they stand for.
+    pub fn any_keyword(&self, predicate: &impl Fn(&SearchKeyword) -> bool) -> bool {
+        match self {
+            ParsedSearch::Or(operands) | ParsedSearch::And(operands) => operands
+                .iter()
+                .any(|operand| operand.any_keyword(predicate)),
+            ParsedSearch::Negated(_, operand) => operand.any_keyword(predicate),
+            ParsedSearch::Keyword(SearchKeyword::ShortcutQuery(shortcut_query)) => {
+                predicate(&SearchKeyword::ShortcutQuery(shortcut_query.clone()))
+                    || shortcut_query.search.any_keyword(predicate)
+            }
+            ParsedSearch::Keyword(keyword) => predicate(keyword),
+        }
+    }
+
+    // Finds the first keyword, in the order they were written, that `find`
+    // picks something out of. This is how the options for the whole search are
+    // read.
+    pub fn find_keyword<T>(&self, find: &impl Fn(&SearchKeyword) -> Option<T>) -> Option<T> {
+        match self {
+            ParsedSearch::Or(operands) | ParsedSearch::And(operands) => operands
+                .iter()
+                .find_map(|operand| operand.find_keyword(find)),
+            ParsedSearch::Negated(_, operand) => operand.find_keyword(find),
+            ParsedSearch::Keyword(keyword) => find(keyword),
+        }
+    }
+
+    // Like `any_keyword`, but skips keywords that have been negated, for
+    // defaults that only change when a search asks for something.
+    pub fn any_wanted_keyword(&self, predicate: &impl Fn(&SearchKeyword) -> bool) -> bool {
+        match self {
+            ParsedSearch::Or(operands) | ParsedSearch::And(operands) => operands
+                .iter()
+                .any(|operand| operand.any_wanted_keyword(predicate)),
+            ParsedSearch::Negated(true, _) => false,
+            ParsedSearch::Negated(false, operand) => operand.any_wanted_keyword(predicate),
+            ParsedSearch::Keyword(SearchKeyword::ShortcutQuery(shortcut_query)) => {
+                !shortcut_query.negated && shortcut_query.search.any_wanted_keyword(predicate)
+            }
+            ParsedSearch::Keyword(keyword) => predicate(keyword),
+        }
+    }
+}
+
 pub fn parsed_search(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
     or.parse(input)
 }
@@ -65,24 +172,76 @@ fn negated(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
 
 fn search_keyword(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
     alt((
+        card_keyword,
+        print_keyword,
+        option_keyword,
+        oracle_query,
+        // Name must be the last parser since it's a bit of a catch-all.
+        name,
+    ))
+    .parse(input)
+}
+
+// alt can "only" take 21 parsers at a time, so the keywords are split between
+// ones about the card itself, ones about a specific printing, and options for
+// the whole search.
+fn card_keyword(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    alt((
+        color_count_query,
         color_query,
         color_identity_query,
-        power_query,
+        indicator_query,
+        stat_query,
+        mana_value_query,
+        mana_cost_query,
+        is_query,
+        shortcut_query,
+        produces_query,
+        devotion_query,
         type_line_query,
         keyword_query,
-        oracle_query,
-        // Name must be the last parser since it's a bit of a catch-all.
-        name,
+        legality_query,
+    ))
+    .parse(input)
+}
+
+fn print_keyword(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    alt((
+        set_type_query,
+        set_query,
+        collector_number_query,
+        rarity_query,
+        artists_query,
+        artist_query,
+        flavor_query,
+        watermark_query,
+        year_query,
+        date_query,
+        price_query,
+        cheapest_query,
+        language_query,
+        in_query,
+        prints_query,
+        game_query,
+        id_query,
+        border_query,
+        frame_query,
+        stamp_query,
     ))
     .parse(input)
 }
 
+fn option_keyword(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    alt((include_query, order_query, direction_query, unique_query)).parse(input)
+}
+
 #[cfg(test)]
 mod test {
     use super::ParsedSearch;
     use crate::search::{
-        color::ColorOperand, parsed_search::parsed_search, type_line_query::TypeLineQuery,
-        ColorOperator, ColorQuery, PowerOperand, PowerOperator, PowerQuery,
+        color::ColorOperand, numeric_operator::NumericOperator, parsed_search::parsed_search,
+        type_line_query::TypeLineQuery, ColorOperator, ColorQuery, Stat, StatOperand, StatQuery,
+        TextOperand,
     };
 
     fn test_or(inside: ParsedSearch) -> ParsedSearch {
@@ -145,16 +304,17 @@ mod test {
             ),
             test_negated(
                 false,
-                ParsedSearch::power_query(PowerQuery {
-                    operator: PowerOperator::LessThan,
-                    operand: PowerOperand::Number("3".to_string()),
+                ParsedSearch::stat_query(StatQuery {
+                    stat: Stat::Power,
+                    operator: NumericOperator::LessThan,
+                    operand: StatOperand::Number("3".to_string()),
                     negated: false,
                 }),
             ),
             test_negated(
                 false,
                 ParsedSearch::type_line(TypeLineQuery {
-                    operand: "creature".to_string(),
+                    operand: TextOperand::Text("creature".to_string()),
                     negated: false,
                 }),
             ),
@@ -178,9 +338,10 @@ mod test {
                 ),
                 test_negated(
                     false,
-                    ParsedSearch::power_query(PowerQuery {
-                        operator: PowerOperator::LessThan,
-                        operand: PowerOperand::Number("3".to_string()),
+                    ParsedSearch::stat_query(StatQuery {
+                        stat: Stat::Power,
+                        operator: NumericOperator::LessThan,
+                        operand: StatOperand::Number("3".to_string()),
                         negated: false,
                     }),
                 ),
@@ -0,0 +1,157 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1},
+    combinator::recognize,
+    sequence::tuple,
+    IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Currency {
+    Usd,
+    Eur,
+    Tix,
+}
+
+impl Currency {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Usd => "USD",
+            Self::Eur => "EUR",
+            Self::Tix => "TIX",
+        }
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Usd => write!(f, "usd"),
+            Self::Eur => write!(f, "eur"),
+            Self::Tix => write!(f, "tix"),
+        }
+    }
+}
+
+fn currency(input: &str) -> IResult<&str, Currency, ErrorTree<&str>> {
+    alt((
+        tag_no_case("usd").value(Currency::Usd),
+        tag_no_case("eur").value(Currency::Eur),
+        tag_no_case("tix").value(Currency::Tix),
+    ))
+    .parse(input)
+}
+
+// You can find prints within certain usd, eur, tix price ranges by comparing
+// them with a numeric expression, e.g. usd>=0.50 or tix>15.00.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriceQuery {
+    pub currency: Currency,
+    pub operator: NumericOperator,
+    pub price: String,
+}
+
+// A price is a number with an optional decimal part, like 15 or 0.50.
+fn price(input: &str) -> IResult<&str, &str, ErrorTree<&str>> {
+    recognize(tuple((digit1, tuple((char('.'), digit1)).opt()))).parse(input)
+}
+
+pub fn price_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((currency, numeric_operator, price))
+        .map(|(currency, operator, price)| PriceQuery {
+            currency,
+            operator,
+            price: price.to_string(),
+        })
+        .map(ParsedSearch::price_query)
+        .parse(input)
+}
+
+// You can find the cheapest print of each card with cheapest:usd,
+// cheapest:eur, and cheapest:tix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheapestQuery {
+    pub currency: Currency,
+}
+
+pub fn cheapest_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("cheapest:"), currency))
+        .map(|(_, currency)| CheapestQuery { currency })
+        .map(ParsedSearch::cheapest_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn price_query(price_query: PriceQuery) -> Self {
+        Self::Keyword(SearchKeyword::PriceQuery(price_query))
+    }
+    pub fn cheapest_query(cheapest_query: CheapestQuery) -> Self {
+        Self::Keyword(SearchKeyword::CheapestQuery(cheapest_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl PriceQuery {
+        pub fn new(currency: Currency, operator: NumericOperator, price: &str) -> Self {
+            Self {
+                currency,
+                operator,
+                price: price.to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn tix_greater_than() {
+        let (_, actual) = price_query("tix>15.00").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::price_query(PriceQuery::new(
+                Currency::Tix,
+                NumericOperator::GreaterThan,
+                "15.00"
+            ))
+        );
+    }
+
+    #[test]
+    fn eur_whole_number() {
+        let (_, actual) = price_query("EUR<=2").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::price_query(PriceQuery::new(
+                Currency::Eur,
+                NumericOperator::LessThanOrEqual,
+                "2"
+            ))
+        );
+    }
+
+    #[test]
+    fn price_requires_a_number() {
+        assert!(price_query("usd>cheap").is_err());
+    }
+
+    #[test]
+    fn cheapest_usd() {
+        let (_, actual) = cheapest_query("cheapest:usd").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::cheapest_query(CheapestQuery {
+                currency: Currency::Usd
+            })
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, character::complete::digit1, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// What's being counted across all of a card's printings. The paper variants
+// leave out prints that were only released digitally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintCount {
+    Prints,
+    Sets,
+    PaperPrints,
+    PaperSets,
+}
+
+impl PrintCount {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Prints => "number of prints",
+            Self::Sets => "number of sets",
+            Self::PaperPrints => "number of paper prints",
+            Self::PaperSets => "number of paper sets",
+        }
+    }
+}
+
+impl Display for PrintCount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prints => write!(f, "prints"),
+            Self::Sets => write!(f, "sets"),
+            Self::PaperPrints => write!(f, "paperprints"),
+            Self::PaperSets => write!(f, "papersets"),
+        }
+    }
+}
+
+// You can compare the number of times a card has been printed with syntax
+// like prints=1, or the number of sets it has been in with sets=1. These can
+// also be compared including only paper sets with paperprints=1 and
+// papersets=1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintsQuery {
+    pub count: PrintCount,
+    pub operator: NumericOperator,
+    pub operand: String,
+}
+
+fn print_count(input: &str) -> IResult<&str, PrintCount, ErrorTree<&str>> {
+    alt((
+        tag_no_case("paperprints").value(PrintCount::PaperPrints),
+        tag_no_case("papersets").value(PrintCount::PaperSets),
+        tag_no_case("prints").value(PrintCount::Prints),
+        tag_no_case("sets").value(PrintCount::Sets),
+    ))
+    .parse(input)
+}
+
+pub fn prints_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((print_count, numeric_operator, digit1))
+        .map(|(count, operator, operand)| PrintsQuery {
+            count,
+            operator,
+            operand: operand.to_string(),
+        })
+        .map(ParsedSearch::prints_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn prints_query(prints_query: PrintsQuery) -> Self {
+        Self::Keyword(SearchKeyword::PrintsQuery(prints_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl PrintsQuery {
+        pub fn new(count: PrintCount, operator: NumericOperator, operand: &str) -> Self {
+            Self {
+                count,
+                operator,
+                operand: operand.to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn prints_equal() {
+        let (_, actual) = prints_query("prints=1").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::prints_query(PrintsQuery::new(
+                PrintCount::Prints,
+                NumericOperator::Equal,
+                "1"
+            ))
+        );
+    }
+
+    #[test]
+    fn sets_greater_than_or_equal() {
+        let (_, actual) = prints_query("sets>=20").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::prints_query(PrintsQuery::new(
+                PrintCount::Sets,
+                NumericOperator::GreaterThanOrEqual,
+                "20"
+            ))
+        );
+    }
+
+    #[test]
+    fn papersets() {
+        let (_, actual) = prints_query("papersets=1").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::prints_query(PrintsQuery::new(
+                PrintCount::PaperSets,
+                NumericOperator::Equal,
+                "1"
+            ))
+        );
+    }
+
+    #[test]
+    fn set_is_not_a_count() {
+        assert!(prints_query("set:war").is_err());
+    }
+}
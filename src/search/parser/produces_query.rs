@@ -0,0 +1,65 @@
+use nom::{bytes::complete::tag_no_case, sequence::tuple, IResult, Parser};
+use nom_supreme::error::ErrorTree;
+
+use super::{
+    color::{color, ColorOperand},
+    color_query::{color_operator, ColorOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// You can also find cards that produce specific types of mana, with produces:
+// The colors are compared the same way as they are for c: or color:
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProducesQuery {
+    pub operator: ColorOperator,
+    pub operand: ColorOperand,
+}
+
+pub fn produces_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("produces"), color_operator, color))
+        .map(|(_, operator, operand)| ProducesQuery { operator, operand })
+        .map(ParsedSearch::produces_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn produces_query(produces_query: ProducesQuery) -> Self {
+        Self::Keyword(SearchKeyword::ProducesQuery(produces_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl ProducesQuery {
+        pub fn new(operator: ColorOperator, operand: ColorOperand) -> Self {
+            Self { operator, operand }
+        }
+    }
+
+    #[test]
+    fn produces_equal_wu() {
+        let (_, actual) = produces_query("produces=wu").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::produces_query(ProducesQuery::new(
+                ColorOperator::Equal,
+                ColorOperand::Azorius
+            ))
+        );
+    }
+
+    #[test]
+    fn produces_colon_green() {
+        let (_, actual) = produces_query("produces:g").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::produces_query(ProducesQuery::new(
+                ColorOperator::Colon,
+                ColorOperand::Green
+            ))
+        );
+    }
+}
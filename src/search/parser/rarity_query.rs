@@ -0,0 +1,209 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{
+    numeric_operator::{numeric_operator, NumericOperator},
+    parsed_search::SearchKeyword,
+    ParsedSearch,
+};
+
+// Use r: or rarity: to find cards by their print rarity. You can search for
+// common, uncommon, rare, special, mythic, and bonus. You can also use
+// comparison operators like < and >=, which compare common < uncommon < rare <
+// mythic. Special and bonus cards are only found by asking for them by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RarityQuery {
+    pub operator: NumericOperator,
+    pub operand: Rarity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Special,
+    Mythic,
+    Bonus,
+}
+
+impl Ord for Rarity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.order().cmp(&other.order())
+    }
+}
+impl PartialOrd for Rarity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Rarity {
+    pub fn all() -> Vec<Rarity> {
+        vec![
+            Rarity::Common,
+            Rarity::Uncommon,
+            Rarity::Rare,
+            Rarity::Special,
+            Rarity::Mythic,
+            Rarity::Bonus,
+        ]
+    }
+    // This is the same ordering Scryfall uses when sorting by rarity.
+    pub fn order(&self) -> usize {
+        match self {
+            Rarity::Common => 0,
+            Rarity::Uncommon => 1,
+            Rarity::Rare => 2,
+            Rarity::Special => 3,
+            Rarity::Mythic => 4,
+            Rarity::Bonus => 5,
+        }
+    }
+    // Where the rarity falls when comparing with < and >. Special and bonus
+    // aren't part of the scale.
+    fn rank(&self) -> Option<usize> {
+        match self {
+            Rarity::Common => Some(0),
+            Rarity::Uncommon => Some(1),
+            Rarity::Rare => Some(2),
+            Rarity::Mythic => Some(3),
+            Rarity::Special | Rarity::Bonus => None,
+        }
+    }
+}
+
+impl RarityQuery {
+    pub fn matches(&self, rarity: &Rarity) -> bool {
+        match self.operator {
+            NumericOperator::Colon | NumericOperator::Equal | NumericOperator::NotEqual => {
+                self.operator.compare(rarity, &self.operand)
+            }
+            _ => match (rarity.rank(), self.operand.rank()) {
+                (Some(rarity), Some(operand)) => self.operator.compare(&rarity, &operand),
+                _ => false,
+            },
+        }
+    }
+}
+
+// The name is the same as the rarity Scryfall stores for a card.
+impl Display for Rarity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rarity = match self {
+            Rarity::Common => "common",
+            Rarity::Uncommon => "uncommon",
+            Rarity::Rare => "rare",
+            Rarity::Special => "special",
+            Rarity::Mythic => "mythic",
+            Rarity::Bonus => "bonus",
+        };
+        write!(f, "{}", rarity)
+    }
+}
+
+fn rarity(input: &str) -> IResult<&str, Rarity, ErrorTree<&str>> {
+    alt((
+        tag_no_case("common").value(Rarity::Common),
+        tag_no_case("uncommon").value(Rarity::Uncommon),
+        tag_no_case("rare").value(Rarity::Rare),
+        tag_no_case("special").value(Rarity::Special),
+        tag_no_case("mythic").value(Rarity::Mythic),
+        tag_no_case("bonus").value(Rarity::Bonus),
+        tag_no_case("c").value(Rarity::Common),
+        tag_no_case("u").value(Rarity::Uncommon),
+        tag_no_case("r").value(Rarity::Rare),
+        tag_no_case("s").value(Rarity::Special),
+        tag_no_case("m").value(Rarity::Mythic),
+        tag_no_case("b").value(Rarity::Bonus),
+    ))
+    .parse(input)
+}
+
+pub fn rarity_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        tag_no_case("rarity").or(tag_no_case("r")),
+        numeric_operator,
+        rarity,
+    ))
+    .map(|(_, operator, operand)| RarityQuery { operator, operand })
+    .map(ParsedSearch::rarity_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn rarity_query(rarity_query: RarityQuery) -> Self {
+        Self::Keyword(SearchKeyword::RarityQuery(rarity_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl RarityQuery {
+        pub fn new(operator: NumericOperator, operand: Rarity) -> Self {
+            Self { operator, operand }
+        }
+    }
+
+    #[test]
+    fn test_r_common() {
+        let (_, actual) = rarity_query("r:common").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::rarity_query(RarityQuery::new(NumericOperator::Colon, Rarity::Common))
+        );
+    }
+
+    #[test]
+    fn test_r_greater_than_or_equal_r() {
+        let (_, actual) = rarity_query("r>=r").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::rarity_query(RarityQuery::new(
+                NumericOperator::GreaterThanOrEqual,
+                Rarity::Rare
+            ))
+        );
+    }
+
+    #[test]
+    fn test_rarity_less_than_mythic() {
+        let (_, actual) = rarity_query("rarity<mythic").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::rarity_query(RarityQuery::new(NumericOperator::LessThan, Rarity::Mythic))
+        );
+    }
+
+    #[test]
+    fn test_special_and_bonus_are_not_compared() {
+        let at_least_rare = RarityQuery::new(NumericOperator::GreaterThanOrEqual, Rarity::Rare);
+        assert!(at_least_rare.matches(&Rarity::Mythic));
+        assert!(!at_least_rare.matches(&Rarity::Special));
+        assert!(!at_least_rare.matches(&Rarity::Bonus));
+        let special = RarityQuery::new(NumericOperator::Colon, Rarity::Special);
+        assert!(special.matches(&Rarity::Special));
+        let below_special = RarityQuery::new(NumericOperator::LessThan, Rarity::Special);
+        assert!(Rarity::all()
+            .iter()
+            .all(|rarity| !below_special.matches(rarity)));
+    }
+
+    #[test]
+    fn test_sort_rarities() {
+        let mut rarities = vec![
+            Rarity::Bonus,
+            Rarity::Mythic,
+            Rarity::Common,
+            Rarity::Rare,
+            Rarity::Uncommon,
+            Rarity::Special,
+        ];
+        rarities.sort();
+        assert_eq!(rarities, Rarity::all());
+    }
+}
@@ -0,0 +1,102 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case};
+
+use super::{name::quoted_or_until_space, parsed_search::SearchKeyword, ParsedSearch};
+
+// Use s:, e:, set:, or edition: to find cards using their Magic set code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetQuery {
+    pub set_code: String,
+}
+
+// You can search for cards based on the type of product they appear in, e.g.
+// st:core, st:expansion, st:masters or st:funny.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetTypeQuery {
+    pub set_type: String,
+}
+
+pub fn set_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        alt((
+            tag_no_case("set:"),
+            tag_no_case("s:"),
+            tag_no_case("edition:"),
+            tag_no_case("e:"),
+        )),
+        quoted_or_until_space,
+    ))
+    .map(|(_, set_code)| SetQuery {
+        set_code: set_code.to_lowercase(),
+    })
+    .map(ParsedSearch::set_query)
+    .parse(input)
+}
+
+pub fn set_type_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((tag_no_case("st:"), quoted_or_until_space))
+        .map(|(_, set_type)| SetTypeQuery {
+            set_type: set_type.to_lowercase(),
+        })
+        .map(ParsedSearch::set_type_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn set_query(set_query: SetQuery) -> Self {
+        Self::Keyword(SearchKeyword::SetQuery(set_query))
+    }
+    pub fn set_type_query(set_type_query: SetTypeQuery) -> Self {
+        Self::Keyword(SearchKeyword::SetTypeQuery(set_type_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_e_war() {
+        let (_, actual) = set_query("e:war").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::set_query(SetQuery {
+                set_code: "war".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_edition_is_lowercased() {
+        let (_, actual) = set_query("edition:M15").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::set_query(SetQuery {
+                set_code: "m15".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_s_and_set() {
+        let (_, short) = set_query("s:ktk").unwrap();
+        let (_, long) = set_query("set:ktk").unwrap();
+        assert_eq!(short, long);
+    }
+
+    #[test]
+    fn test_st_duel_deck() {
+        let (_, actual) = set_type_query("st:duel_deck").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::set_type_query(SetTypeQuery {
+                set_type: "duel_deck".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_st_is_not_a_set() {
+        assert!(set_query("st:core").is_err());
+    }
+}
@@ -0,0 +1,322 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+    sync::{Once, RwLock},
+};
+
+use lazy_static::lazy_static;
+use nom::{bytes::complete::take_while1, combinator::map_opt, sequence::tuple, IResult, Parser};
+use nom_supreme::error::ErrorTree;
+use regex::Regex;
+use thiserror::Error;
+
+use super::{
+    is_query::{is_or_not, IsPredicate},
+    parsed_search::SearchKeyword,
+    search, ParsedSearch,
+};
+
+// The built-in shortcuts, in the same format as a user's shortcuts file.
+static BUILT_IN: &str = include_str!("shortcuts.txt");
+
+lazy_static! {
+    // Every shortcut that's been defined, already parsed into the search it
+    // stands for.
+    static ref SHORTCUTS: RwLock<HashMap<String, ParsedSearch>> = RwLock::new(HashMap::new());
+    static ref REFERENCE: Regex = Regex::new(r"(?i)\b(?:is|not):(\w+)").unwrap();
+}
+
+static LOAD_BUILT_IN: Once = Once::new();
+
+#[derive(Debug, Error)]
+pub enum ShortcutError {
+    #[error("Line {line} should look like `name = query`")]
+    Malformed { line: usize },
+    #[error("Shortcut names can only contain letters, numbers and underscores: {0}")]
+    InvalidName(String),
+    #[error("is:{0} is already defined")]
+    AlreadyDefined(String),
+    #[error("is:{name} uses is:{reference}, which isn't defined")]
+    Undefined { name: String, reference: String },
+    #[error("Shortcuts can't use themselves: {}", .0.join(" -> "))]
+    Cyclic(Vec<String>),
+    #[error("is:{name} stands for a query that can't be parsed: {query}")]
+    Unparsable { name: String, query: String },
+    #[error("Couldn't read shortcuts: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+// Shortcuts are written one per line as `name = query`. Blank lines and lines
+// starting with # are skipped.
+pub fn parse_definitions(text: &str) -> Result<Vec<(String, String)>, ShortcutError> {
+    text.lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, definition)| {
+            definition
+                .split_once('=')
+                .map(|(name, query)| (name.trim().to_string(), query.trim().to_string()))
+                .filter(|(name, query)| !name.is_empty() && !query.is_empty())
+                .ok_or(ShortcutError::Malformed { line })
+        })
+        .collect()
+}
+
+// The names of the other shortcuts or predicates a query uses.
+fn references(query: &str) -> Vec<String> {
+    REFERENCE
+        .captures_iter(query)
+        .map(|captures| captures[1].to_lowercase())
+        .collect()
+}
+
+// Orders the new shortcuts so that each one comes after any of the others it
+// uses, which is the order they have to be parsed in.
+fn visit(
+    name: &str,
+    pending: &HashMap<String, String>,
+    defined: &HashSet<String>,
+    visiting: &mut Vec<String>,
+    order: &mut Vec<String>,
+) -> Result<(), ShortcutError> {
+    if order.iter().any(|done| done == name) {
+        return Ok(());
+    }
+    if let Some(start) = visiting.iter().position(|other| other == name) {
+        let mut cycle = visiting[start..].to_vec();
+        cycle.push(name.to_string());
+        return Err(ShortcutError::Cyclic(cycle));
+    }
+    visiting.push(name.to_string());
+    for reference in references(&pending[name]) {
+        if pending.contains_key(&reference) {
+            visit(&reference, pending, defined, visiting, order)?;
+        } else if !defined.contains(&reference) && IsPredicate::from_name(&reference).is_none() {
+            return Err(ShortcutError::Undefined {
+                name: name.to_string(),
+                reference,
+            });
+        }
+    }
+    visiting.pop();
+    order.push(name.to_string());
+    Ok(())
+}
+
+// Adds shortcuts to the registry. Either all of them are added, or none are
+// and the first problem found is returned.
+pub fn define(definitions: Vec<(String, String)>) -> Result<(), ShortcutError> {
+    let defined = SHORTCUTS
+        .read()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect::<HashSet<_>>();
+    let mut names = vec![];
+    let mut pending = HashMap::new();
+    for (name, query) in definitions {
+        let name = name.to_lowercase();
+        if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(ShortcutError::InvalidName(name));
+        }
+        if IsPredicate::from_name(&name).is_some()
+            || defined.contains(&name)
+            || pending.contains_key(&name)
+        {
+            return Err(ShortcutError::AlreadyDefined(name));
+        }
+        names.push(name.clone());
+        pending.insert(name, query);
+    }
+
+    let mut order = vec![];
+    for name in &names {
+        visit(name, &pending, &defined, &mut vec![], &mut order)?;
+    }
+
+    // Shortcuts are parsed with the ones they use already in the registry.
+    let mut added = vec![];
+    for name in order {
+        let query = &pending[&name];
+        match search(query) {
+            Ok(parsed_search) => {
+                SHORTCUTS
+                    .write()
+                    .unwrap()
+                    .insert(name.clone(), parsed_search);
+                added.push(name);
+            }
+            Err(_) => {
+                let mut shortcuts = SHORTCUTS.write().unwrap();
+                for name in added {
+                    shortcuts.remove(&name);
+                }
+                return Err(ShortcutError::Unparsable {
+                    name,
+                    query: query.clone(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn load_built_in() {
+    LOAD_BUILT_IN.call_once(|| {
+        parse_definitions(BUILT_IN)
+            .and_then(define)
+            .expect("The built-in shortcuts should be valid.");
+    });
+}
+
+// Users can add their own shortcuts in a file using the same format as the
+// built-in ones.
+pub fn load_file(path: &Path) -> Result<(), ShortcutError> {
+    if !path.exists() {
+        return Ok(());
+    }
+    define(parse_definitions(&fs::read_to_string(path)?)?)
+}
+
+fn lookup(name: &str) -> Option<ParsedSearch> {
+    SHORTCUTS.read().unwrap().get(name).cloned()
+}
+
+// A shortcut is used like a predicate, with is:<name> or not:<name>, and
+// stands for the search it was defined as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutQuery {
+    pub name: String,
+    pub negated: bool,
+    pub search: Box<ParsedSearch>,
+}
+
+fn shortcut(input: &str) -> IResult<&str, (String, ParsedSearch), ErrorTree<&str>> {
+    map_opt(
+        take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+        |name: &str| {
+            let name = name.to_lowercase();
+            lookup(&name).map(|search| (name, search))
+        },
+    )
+    .parse(input)
+}
+
+pub fn shortcut_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((is_or_not, shortcut))
+        .map(|(negated, (name, search))| ShortcutQuery {
+            name,
+            negated,
+            search: Box::new(search),
+        })
+        .map(ParsedSearch::shortcut_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn shortcut_query(shortcut_query: ShortcutQuery) -> Self {
+        Self::Keyword(SearchKeyword::ShortcutQuery(shortcut_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definitions(definitions: &[(&str, &str)]) -> Vec<(String, String)> {
+        definitions
+            .iter()
+            .map(|(name, query)| (name.to_string(), query.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn built_in_shortcuts_expand() {
+        load_built_in();
+        let (_, actual) = shortcut_query("is:fetchland").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::shortcut_query(ShortcutQuery {
+                name: "fetchland".to_string(),
+                negated: false,
+                search: Box::new(
+                    search(r#"t:land o:"pay 1 life, sacrifice" o:"search your library""#).unwrap()
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn aliases_expand_to_the_shortcut_they_use() {
+        load_built_in();
+        let (_, karoo) = shortcut_query("not:karoo").unwrap();
+        let ParsedSearch::Keyword(SearchKeyword::ShortcutQuery(karoo)) = karoo else {
+            panic!("Expected a shortcut");
+        };
+        assert!(karoo.negated);
+        assert_eq!(*karoo.search, search("is:bounceland").unwrap());
+    }
+
+    #[test]
+    fn users_can_add_shortcuts() {
+        define(definitions(&[
+            ("test_commander", "t:legendary t:creature"),
+            ("test_green_commander", "is:test_commander c:g"),
+        ]))
+        .unwrap();
+        assert!(shortcut_query("is:test_green_commander").is_ok());
+        assert!(shortcut_query("is:test_Commander").is_ok());
+    }
+
+    #[test]
+    fn cyclic_shortcuts_are_rejected() {
+        let actual = define(definitions(&[
+            ("test_loop_a", "is:test_loop_b"),
+            ("test_loop_b", "t:land is:test_loop_a"),
+        ]));
+        assert!(matches!(
+            actual,
+            Err(ShortcutError::Cyclic(cycle)) if cycle == vec!["test_loop_a", "test_loop_b", "test_loop_a"]
+        ));
+        assert!(lookup("test_loop_a").is_none());
+
+        let actual = define(definitions(&[("test_self", "not:test_self")]));
+        assert!(matches!(actual, Err(ShortcutError::Cyclic(_))));
+    }
+
+    #[test]
+    fn unparsable_shortcuts_are_rejected() {
+        let actual = define(definitions(&[
+            ("test_fine", "t:land"),
+            ("test_broken", "is:test_fine o:/(/"),
+        ]));
+        assert!(matches!(
+            actual,
+            Err(ShortcutError::Unparsable { name, .. }) if name == "test_broken"
+        ));
+        assert!(
+            lookup("test_fine").is_none(),
+            "Nothing is added if any shortcut is rejected."
+        );
+    }
+
+    #[test]
+    fn shortcuts_must_use_defined_names() {
+        let actual = define(definitions(&[("test_unknown", "is:nonsense")]));
+        assert!(matches!(actual, Err(ShortcutError::Undefined { .. })));
+    }
+
+    #[test]
+    fn shortcuts_cannot_replace_predicates() {
+        let actual = define(definitions(&[("Split", "t:instant")]));
+        assert!(matches!(actual, Err(ShortcutError::AlreadyDefined(name)) if name == "split"));
+    }
+
+    #[test]
+    fn definitions_must_have_a_name_and_query() {
+        let actual = parse_definitions("# Comment\n\nfine = t:land\nbroken t:land");
+        assert!(matches!(actual, Err(ShortcutError::Malformed { line: 4 })));
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, combinator::opt, sequence::tuple, IResult, Parser};
+use nom_supreme::{
+    error::ErrorTree,
+    tag::complete::{tag, tag_no_case},
+    ParserExt,
+};
+
+use super::{
+    numeric_operator::{number, numeric_operator, NumericOperator},
+    ParsedSearch,
+};
+use crate::search::SearchKeyword;
+
+// Power, Toughness, and Loyalty
+// You can use numeric expressions (>, <, =, >=, <=, and !=) to find cards with
+// certain power, power/pow, toughness, toughness/tou, total power and
+// toughness, pt/powtou, or starting loyalty, loyalty/loy.
+//
+// You can compare the values with each other or with a provided number.
+//
+// Stats aren't always numbers. Any value that isn't a plain number is compared
+// using its leading number, so * and X count as 0 and 1+* counts as 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatQuery {
+    pub stat: Stat,
+    pub operator: NumericOperator,
+    pub operand: StatOperand,
+    pub negated: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stat {
+    Power,
+    Toughness,
+    Loyalty,
+    Defense,
+    PowerToughness,
+}
+
+impl Stat {
+    pub fn describe(&self) -> &str {
+        match self {
+            Stat::Power => "power",
+            Stat::Toughness => "toughness",
+            Stat::Loyalty => "loyalty",
+            Stat::Defense => "defense",
+            Stat::PowerToughness => "power plus toughness",
+        }
+    }
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Stat::Power => write!(f, "pow"),
+            Stat::Toughness => write!(f, "tou"),
+            Stat::Loyalty => write!(f, "loy"),
+            Stat::Defense => write!(f, "def"),
+            Stat::PowerToughness => write!(f, "pt"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatOperand {
+    Number(String),
+    Stat(Stat),
+}
+
+impl StatOperand {
+    pub fn describe(&self) -> String {
+        match self {
+            StatOperand::Number(num) => num.to_string(),
+            StatOperand::Stat(stat) => stat.describe().to_string(),
+        }
+    }
+}
+
+fn stat(input: &str) -> IResult<&str, Stat, ErrorTree<&str>> {
+    // The longer keywords need to come first since they share prefixes with
+    // the shorter ones.
+    alt((
+        tag_no_case("powtou").value(Stat::PowerToughness),
+        tag_no_case("pt").value(Stat::PowerToughness),
+        tag_no_case("power").value(Stat::Power),
+        tag_no_case("pow").value(Stat::Power),
+        tag_no_case("toughness").value(Stat::Toughness),
+        tag_no_case("tou").value(Stat::Toughness),
+        tag_no_case("loyalty").value(Stat::Loyalty),
+        tag_no_case("loy").value(Stat::Loyalty),
+        tag_no_case("defense").value(Stat::Defense),
+        tag_no_case("def").value(Stat::Defense),
+    ))
+    .parse(input)
+}
+
+fn stat_operand(input: &str) -> IResult<&str, StatOperand, ErrorTree<&str>> {
+    alt((
+        stat.map(StatOperand::Stat),
+        number.map(|num| StatOperand::Number(num.to_string())),
+    ))
+    .parse(input)
+}
+
+pub fn stat_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((opt(tag("-")), stat, numeric_operator, stat_operand))
+        .map(|(negate, stat, operator, operand)| StatQuery {
+            stat,
+            operator,
+            operand,
+            negated: negate.is_some(),
+        })
+        .map(ParsedSearch::stat_query)
+        .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn stat_query(stat: StatQuery) -> Self {
+        Self::Keyword(SearchKeyword::StatQuery(stat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl StatQuery {
+        pub fn new(stat: Stat, operator: NumericOperator, operand: StatOperand) -> Self {
+            Self {
+                stat,
+                operator,
+                operand,
+                negated: false,
+            }
+        }
+    }
+
+    #[test]
+    fn test_power_gt_3() {
+        let (_, actual) = stat_query("pow>3").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::Power,
+                NumericOperator::GreaterThan,
+                StatOperand::Number("3".to_string()),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_power_toughness() {
+        let (_, actual) = stat_query("power<=toughness").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::Power,
+                NumericOperator::LessThanOrEqual,
+                StatOperand::Stat(Stat::Toughness),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_toughness_gt_power() {
+        let (_, actual) = stat_query("tou>pow").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::Toughness,
+                NumericOperator::GreaterThan,
+                StatOperand::Stat(Stat::Power),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_loyalty_equals_3() {
+        let (_, actual) = stat_query("loy=3").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::Loyalty,
+                NumericOperator::Equal,
+                StatOperand::Number("3".to_string()),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_defense_not_equal_loyalty() {
+        let (_, actual) = stat_query("defense!=loyalty").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::Defense,
+                NumericOperator::NotEqual,
+                StatOperand::Stat(Stat::Loyalty),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_powtou_is_not_pow() {
+        let (_, actual) = stat_query("powtou>=10").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::PowerToughness,
+                NumericOperator::GreaterThanOrEqual,
+                StatOperand::Number("10".to_string()),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_power_less_than_negative_one() {
+        let (_, actual) = stat_query("pow<-1").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::Power,
+                NumericOperator::LessThan,
+                StatOperand::Number("-1".to_string()),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_stat_must_be_a_plain_number() {
+        assert!(stat_query("pow>inf").is_err());
+        assert!(stat_query("tou=nan").is_err());
+        let (rest, _) = stat_query("loy>1e400").unwrap();
+        assert_eq!(rest, "e400");
+    }
+
+    #[test]
+    fn test_pt_compared_to_loyalty() {
+        let (_, actual) = stat_query("pt<loy").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::stat_query(StatQuery::new(
+                Stat::PowerToughness,
+                NumericOperator::LessThan,
+                StatOperand::Stat(Stat::Loyalty),
+            ))
+        );
+    }
+}
@@ -4,12 +4,12 @@ use nom_supreme::{
     tag::complete::{tag, tag_no_case},
 };
 
-use super::quoted_or_until_space;
+use super::{text_operand, TextOperand};
 use crate::search::{parsed_search::ParsedSearch, SearchKeyword};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TypeLineQuery {
-    pub operand: String,
+    pub operand: TextOperand,
     pub negated: bool,
 }
 
@@ -18,10 +18,10 @@ pub fn type_line_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&st
         opt(tag("-")),
         alt((tag_no_case("type"), tag_no_case("t"))),
         alt((tag(":"), tag("="))),
-        quoted_or_until_space,
+        text_operand,
     ))
-    .map(|(negate, _, _, comparison)| TypeLineQuery {
-        operand: comparison.to_string(),
+    .map(|(negate, _, _, operand)| TypeLineQuery {
+        operand,
         negated: negate.is_some(),
     })
     .map(ParsedSearch::type_line)
@@ -42,7 +42,7 @@ mod tests {
     fn test_type_line_query_type() {
         let input = r#"type:"Creature - Goblin""#;
         let expected = ParsedSearch::type_line(TypeLineQuery {
-            operand: "Creature - Goblin".to_string(),
+            operand: TextOperand::Text("Creature - Goblin".to_string()),
             negated: false,
         });
         let (_, actual) = type_line_query(input).unwrap();
@@ -53,7 +53,7 @@ mod tests {
     fn test_type_line_query_negated_type() {
         let input = r#"-type:"Sorcery""#;
         let expected = ParsedSearch::type_line(TypeLineQuery {
-            operand: "Sorcery".to_string(),
+            operand: TextOperand::Text("Sorcery".to_string()),
             negated: true,
         });
         let (_, actual) = type_line_query(input).unwrap();
@@ -64,7 +64,7 @@ mod tests {
     fn test_type_line_query_t() {
         let input = r#"t:"Land""#;
         let expected = ParsedSearch::type_line(TypeLineQuery {
-            operand: "Land".to_string(),
+            operand: TextOperand::Text("Land".to_string()),
             negated: false,
         });
         let (_, actual) = type_line_query(input).unwrap();
@@ -75,7 +75,7 @@ mod tests {
     fn test_type_line_query_negated_t() {
         let input = r#"-t:"Enchantment Creature - Human""#;
         let expected = ParsedSearch::type_line(TypeLineQuery {
-            operand: "Enchantment Creature - Human".to_string(),
+            operand: TextOperand::Text("Enchantment Creature - Human".to_string()),
             negated: true,
         });
         let (_, actual) = type_line_query(input).unwrap();
@@ -86,7 +86,18 @@ mod tests {
     fn test_type_line_query_equals_sign() {
         let input = r#"type=Artifact"#;
         let expected = ParsedSearch::type_line(TypeLineQuery {
-            operand: "Artifact".to_string(),
+            operand: TextOperand::Text("Artifact".to_string()),
+            negated: false,
+        });
+        let (_, actual) = type_line_query(input).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_type_line_query_regex() {
+        let input = r"t:/^legendary.*\/\/.*$/";
+        let expected = ParsedSearch::type_line(TypeLineQuery {
+            operand: TextOperand::Regex(r"^legendary.*//.*$".to_string()),
             negated: false,
         });
         let (_, actual) = type_line_query(input).unwrap();
@@ -0,0 +1,83 @@
+use std::fmt::{self, Display, Formatter};
+
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{parsed_search::SearchKeyword, ParsedSearch};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unique {
+    #[default]
+    Cards,
+    Prints,
+    Art,
+}
+
+impl Unique {
+    pub fn describe(&self) -> &str {
+        match self {
+            Self::Cards => "one print of each card",
+            Self::Prints => "every print",
+            Self::Art => "one print of each artwork",
+        }
+    }
+}
+
+impl Display for Unique {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cards => write!(f, "cards"),
+            Self::Prints => write!(f, "prints"),
+            Self::Art => write!(f, "art"),
+        }
+    }
+}
+
+// unique: chooses which prints are shown rather than which cards are found.
+// By default each card is shown once, unique:prints shows every print and
+// unique:art shows one print of each artwork.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniqueQuery {
+    pub unique: Unique,
+}
+
+pub fn unique_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    tuple((
+        tag_no_case("unique:"),
+        alt((
+            tag_no_case("cards").value(Unique::Cards),
+            tag_no_case("prints").value(Unique::Prints),
+            tag_no_case("art").value(Unique::Art),
+        )),
+    ))
+    .map(|(_, unique)| UniqueQuery { unique })
+    .map(ParsedSearch::unique_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn unique_query(unique_query: UniqueQuery) -> Self {
+        Self::Keyword(SearchKeyword::UniqueQuery(unique_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_prints() {
+        let (_, actual) = unique_query("unique:prints").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::unique_query(UniqueQuery {
+                unique: Unique::Prints
+            })
+        );
+    }
+
+    #[test]
+    fn unique_unknown_does_not_parse() {
+        assert!(unique_query("unique:names").is_err());
+    }
+}
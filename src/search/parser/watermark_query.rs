@@ -0,0 +1,51 @@
+use nom::{branch::alt, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag_no_case, ParserExt};
+
+use super::{name::quoted_or_until_space, parsed_search::SearchKeyword, ParsedSearch};
+
+// Search for a card's affiliation watermark using the wm: or watermark:
+// keywords, or match all cards with watermarks using has:watermark.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatermarkQuery {
+    Named(String),
+    Any,
+}
+
+pub fn watermark_query(input: &str) -> IResult<&str, ParsedSearch, ErrorTree<&str>> {
+    alt((
+        tag_no_case("has:watermark").value(WatermarkQuery::Any),
+        tuple((
+            alt((tag_no_case("watermark:"), tag_no_case("wm:"))),
+            quoted_or_until_space,
+        ))
+        .map(|(_, watermark)| WatermarkQuery::Named(watermark.to_lowercase())),
+    ))
+    .map(ParsedSearch::watermark_query)
+    .parse(input)
+}
+
+impl ParsedSearch {
+    pub fn watermark_query(watermark_query: WatermarkQuery) -> Self {
+        Self::Keyword(SearchKeyword::WatermarkQuery(watermark_query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watermark_named() {
+        let (_, actual) = watermark_query("wm:Orzhov").unwrap();
+        assert_eq!(
+            actual,
+            ParsedSearch::watermark_query(WatermarkQuery::Named("orzhov".to_string()))
+        );
+    }
+
+    #[test]
+    fn has_watermark() {
+        let (_, actual) = watermark_query("has:watermark").unwrap();
+        assert_eq!(actual, ParsedSearch::watermark_query(WatermarkQuery::Any));
+    }
+}
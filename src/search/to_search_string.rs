@@ -1,4 +1,33 @@
-use super::{color::ColorOperand, ColorOperator, ColorQuery, ParsedSearch};
+use super::{
+    artist_query::{ArtistQuery, ArtistsQuery},
+    collector_number_query::CollectorNumberQuery,
+    color::ColorOperand,
+    color_count_query::ColorCountQuery,
+    date_query::{DateQuery, YearQuery},
+    flavor_query::FlavorQuery,
+    frame_query::{BorderQuery, FrameQuery, StampQuery},
+    game_query::GameQuery,
+    id_query::IdQuery,
+    in_query::InQuery,
+    include_query::IncludeQuery,
+    is_query::IsQuery,
+    language_query::LanguageQuery,
+    legality_query::LegalityQuery,
+    mana_cost_query::ManaCostQuery,
+    mana_value_query::{ManaValueOperand, ManaValueQuery},
+    name::TextOperand,
+    numeric_operator::NumericOperator,
+    order_query::{DirectionQuery, OrderQuery},
+    price_query::{CheapestQuery, PriceQuery},
+    prints_query::PrintsQuery,
+    produces_query::ProducesQuery,
+    rarity_query::RarityQuery,
+    set_query::{SetQuery, SetTypeQuery},
+    shortcut::ShortcutQuery,
+    unique_query::UniqueQuery,
+    watermark_query::WatermarkQuery,
+    ColorOperator, ColorQuery, ParsedSearch, StatOperand, StatQuery,
+};
 
 trait ToSearchString {
     fn to_search_string(&self) -> String;
@@ -31,12 +60,157 @@ impl ToSearchString for ParsedSearch {
                     operator = operator.to_search_string(),
                     operand = operand.to_search_string()
                 ),
-                super::SearchKeyword::PowerQuery(_) => todo!(),
+                super::SearchKeyword::StatQuery(StatQuery {
+                    stat,
+                    operator,
+                    operand,
+                    ..
+                }) => format!(
+                    "{stat}{operator}{operand}",
+                    operator = operator.to_search_string(),
+                    operand = operand.to_search_string()
+                ),
                 super::SearchKeyword::Name(_) => todo!(),
                 super::SearchKeyword::TypeLineQuery(_) => todo!(),
                 super::SearchKeyword::Keyword(_) => todo!(),
                 super::SearchKeyword::OracleQuery(_) => todo!(),
                 super::SearchKeyword::ColorIdentityQuery(_) => todo!(),
+                super::SearchKeyword::ManaValueQuery(ManaValueQuery { operator, operand }) => {
+                    format!(
+                        "mv{operator}{operand}",
+                        operator = operator.to_search_string(),
+                        operand = operand.to_search_string()
+                    )
+                }
+                super::SearchKeyword::LegalityQuery(LegalityQuery { legality, format }) => {
+                    format!("{legality}:{format}")
+                }
+                super::SearchKeyword::SetQuery(SetQuery { set_code }) => format!("set:{set_code}"),
+                super::SearchKeyword::SetTypeQuery(SetTypeQuery { set_type }) => {
+                    format!("st:{set_type}")
+                }
+                super::SearchKeyword::CollectorNumberQuery(CollectorNumberQuery {
+                    operator,
+                    operand,
+                }) => format!(
+                    "cn{operator}{operand}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::RarityQuery(RarityQuery { operator, operand }) => format!(
+                    "r{operator}{operand}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::ManaCostQuery(ManaCostQuery { operator, operand }) => {
+                    format!(
+                        "m{operator}{operand}",
+                        operator = operator.to_search_string()
+                    )
+                }
+                super::SearchKeyword::ProducesQuery(ProducesQuery { operator, operand }) => {
+                    format!(
+                        "produces{operator}{operand}",
+                        operator = operator.to_search_string(),
+                        operand = operand.to_search_string()
+                    )
+                }
+                super::SearchKeyword::DevotionQuery(dq) => dq.to_string(),
+                super::SearchKeyword::ArtistQuery(ArtistQuery { artist }) => {
+                    format!(
+                        "a:{artist}",
+                        artist = TextOperand::Text(artist.clone()).to_search_string()
+                    )
+                }
+                super::SearchKeyword::IsQuery(IsQuery { predicate, negated }) => {
+                    if *negated {
+                        format!("not:{predicate}")
+                    } else {
+                        format!("is:{predicate}")
+                    }
+                }
+                super::SearchKeyword::ArtistsQuery(ArtistsQuery { operator, count }) => format!(
+                    "artists{operator}{count}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::FlavorQuery(FlavorQuery { flavor_text }) => {
+                    format!(
+                        "ft:{flavor_text}",
+                        flavor_text = flavor_text.to_search_string()
+                    )
+                }
+                super::SearchKeyword::WatermarkQuery(WatermarkQuery::Named(watermark)) => {
+                    format!("wm:{watermark}")
+                }
+                super::SearchKeyword::WatermarkQuery(WatermarkQuery::Any) => {
+                    "has:watermark".to_string()
+                }
+                super::SearchKeyword::PriceQuery(PriceQuery {
+                    currency,
+                    operator,
+                    price,
+                }) => format!(
+                    "{currency}{operator}{price}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::CheapestQuery(CheapestQuery { currency }) => {
+                    format!("cheapest:{currency}")
+                }
+                super::SearchKeyword::LanguageQuery(LanguageQuery::Language(language)) => {
+                    format!("lang:{language}")
+                }
+                super::SearchKeyword::LanguageQuery(LanguageQuery::Any) => "lang:any".to_string(),
+                super::SearchKeyword::IncludeQuery(IncludeQuery::Extras) => {
+                    "include:extras".to_string()
+                }
+                super::SearchKeyword::OrderQuery(OrderQuery { order }) => format!("order:{order}"),
+                super::SearchKeyword::UniqueQuery(UniqueQuery { unique }) => {
+                    format!("unique:{unique}")
+                }
+                super::SearchKeyword::DirectionQuery(DirectionQuery { direction }) => {
+                    format!("direction:{direction}")
+                }
+                super::SearchKeyword::InQuery(InQuery::Language(language)) => {
+                    format!("in:{language}")
+                }
+                super::SearchKeyword::InQuery(InQuery::Game(game)) => format!("in:{game}"),
+                super::SearchKeyword::BorderQuery(BorderQuery { border }) => {
+                    format!("border:{border}")
+                }
+                super::SearchKeyword::FrameQuery(FrameQuery { frame }) => format!("frame:{frame}"),
+                super::SearchKeyword::StampQuery(StampQuery { stamp }) => format!("stamp:{stamp}"),
+                super::SearchKeyword::ShortcutQuery(ShortcutQuery { name, negated, .. }) => {
+                    if *negated {
+                        format!("not:{name}")
+                    } else {
+                        format!("is:{name}")
+                    }
+                }
+                super::SearchKeyword::GameQuery(GameQuery { game }) => format!("game:{game}"),
+                super::SearchKeyword::IdQuery(IdQuery { game_id, id }) => format!("{game_id}:{id}"),
+                super::SearchKeyword::ColorCountQuery(ColorCountQuery {
+                    count,
+                    operator,
+                    operand,
+                }) => format!(
+                    "{count}{operator}{operand}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::IndicatorQuery(_) => "has:indicator".to_string(),
+                super::SearchKeyword::PrintsQuery(PrintsQuery {
+                    count,
+                    operator,
+                    operand,
+                }) => format!(
+                    "{count}{operator}{operand}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::YearQuery(YearQuery { operator, year }) => format!(
+                    "year{operator}{year}",
+                    operator = operator.to_search_string()
+                ),
+                super::SearchKeyword::DateQuery(DateQuery { operator, operand }) => format!(
+                    "date{operator}{operand}",
+                    operator = operator.to_search_string()
+                ),
             },
         }
     }
@@ -57,6 +231,43 @@ impl ToSearchString for ColorOperator {
     }
 }
 
+impl ToSearchString for NumericOperator {
+    fn to_search_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ToSearchString for ManaValueOperand {
+    fn to_search_string(&self) -> String {
+        match self {
+            ManaValueOperand::Number(num) => num.to_string(),
+            ManaValueOperand::Even => "even".to_string(),
+            ManaValueOperand::Odd => "odd".to_string(),
+        }
+    }
+}
+
+impl ToSearchString for TextOperand {
+    fn to_search_string(&self) -> String {
+        match self {
+            // Quoted text can't contain the quote it's wrapped in, so text
+            // with a double quote in it is wrapped in single quotes instead.
+            TextOperand::Text(text) if text.contains('"') => format!("'{text}'"),
+            TextOperand::Text(text) => format!("\"{text}\""),
+            TextOperand::Regex(_) => self.to_string(),
+        }
+    }
+}
+
+impl ToSearchString for StatOperand {
+    fn to_search_string(&self) -> String {
+        match self {
+            StatOperand::Number(num) => num.to_string(),
+            StatOperand::Stat(stat) => stat.to_string(),
+        }
+    }
+}
+
 impl ToSearchString for ColorOperand {
     fn to_search_string(&self) -> String {
         match self {
@@ -118,4 +329,291 @@ mod test {
         let actual = parsed.to_search_string();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn mana_value_keyword_happy_path() {
+        let expected = "mv>=3";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn mana_value_upgrade_keyword_happy_path() {
+        let expected = "mv:odd";
+        let input = "manavalue:odd";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn stat_keyword_happy_path() {
+        let expected = "pow>tou";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn stat_upgrade_keyword_happy_path() {
+        let expected = "pt>=loy";
+        let input = "powtou>=loyalty";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn legality_keyword_happy_path() {
+        let expected = "banned:legacy";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn legality_upgrade_keyword_happy_path() {
+        let expected = "f:commander";
+        let input = "format:commander";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn set_upgrade_keyword_happy_path() {
+        let expected = "set:war";
+        let input = "e:WAR";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn rarity_upgrade_keyword_happy_path() {
+        let expected = "r>=rare";
+        let input = "rarity>=r";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn mana_cost_keyword_happy_path() {
+        let expected = "m>{2}{W}{U}";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn mana_cost_upgrade_keyword_happy_path() {
+        let expected = "m:{2}{W}{W}";
+        let input = "mana:2WW";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn is_keyword_happy_path() {
+        let expected = "is:phyrexian";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn produces_keyword_happy_path() {
+        let expected = "produces:green";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn devotion_upgrade_keyword_happy_path() {
+        let expected = "devotion>={U/B}{U/B}";
+        let input = "devotion>={u/b}{b/u}";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn watermark_upgrade_keyword_happy_path() {
+        let expected = "wm:orzhov";
+        let input = "watermark:Orzhov";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn artist_and_flavor_keyword_happy_path() {
+        for expected in [r#"a:"John Avon""#, r#"ft:"mishra""#, "ft:/^the/"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn artist_and_flavor_quotes_and_slashes_round_trip() {
+        for expected in [
+            r#"ft:'say "hi"'"#,
+            r"ft:/fire \/\/ ice/",
+            r#"a:'Jim "Pav" Pavelec'"#,
+        ] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn artist_and_flavor_upgrade_keyword_happy_path() {
+        let expected = r#"a:"avon" AND ft:"ruins""#;
+        let input = "artist:avon flavor:ruins";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn not_keyword_happy_path() {
+        let expected = "not:dfc";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn year_keyword_happy_path() {
+        let expected = "year<=1994";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn date_keyword_happy_path() {
+        for expected in ["date>=2015-08-18", "date>ori"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn price_keyword_happy_path() {
+        for expected in ["usd>=0.50", "tix>15", "cheapest:eur"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn language_keyword_happy_path() {
+        for expected in ["lang:ja", "lang:any", "in:ru"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn language_upgrade_keyword_happy_path() {
+        let expected = "lang:ja";
+        let input = "language:japanese";
+        let parsed = search(input).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn prints_keyword_happy_path() {
+        for expected in ["prints=1", "sets>=20", "paperprints<3", "papersets=1"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn game_keyword_happy_path() {
+        for expected in [
+            "game:paper",
+            "in:arena",
+            "is:digital",
+            "arenaid:67330",
+            "mtgoid:12345",
+        ] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn frame_keyword_happy_path() {
+        for expected in [
+            "border:white",
+            "frame:2003",
+            "frame:legendary",
+            "stamp:oval",
+            "is:foil",
+        ] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn shortcut_keyword_happy_path() {
+        crate::search::shortcut::load_built_in();
+        for expected in ["is:shockland", "not:karoo"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn include_keyword_happy_path() {
+        let expected = "include:extras";
+        let parsed = search(expected).unwrap();
+        let actual = parsed.to_search_string();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn order_keyword_happy_path() {
+        for expected in ["order:usd", "direction:desc"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn unique_keyword_happy_path() {
+        for expected in ["unique:cards", "unique:prints", "unique:art"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn color_count_keyword_happy_path() {
+        for expected in ["c=2", "id>=3", "has:indicator"] {
+            let parsed = search(expected).unwrap();
+            let actual = parsed.to_search_string();
+            assert_eq!(expected, actual);
+        }
+    }
 }
@@ -3,7 +3,12 @@ use iced::{
     Element, Length,
 };
 
-use super::{ParsedSearch, Search};
+use super::{
+    date_query::DateOperand, in_query::InQuery, include_query::IncludeQuery,
+    language_query::LanguageQuery, mana_value_query::ManaValueOperand,
+    numeric_operator::NumericOperator, order_query::Direction, watermark_query::WatermarkQuery,
+    Name, ParsedSearch, Search, TextOperand,
+};
 use crate::{Message, SPACING_MEDIUM, SPACING_SMALL};
 
 impl Search {
@@ -20,22 +25,236 @@ impl Search {
                     let color = ciq.operand.describe();
                     text(format!("color identity {operator} {color}")).into()
                 }
-                super::SearchKeyword::PowerQuery(pq) => {
+                super::SearchKeyword::StatQuery(sq) => {
+                    let stat = sq.stat.describe();
+                    let operator = sq.operator.describe();
+                    let operand = sq.operand.describe();
+                    text(format!("{stat} is {operator} {operand}")).into()
+                }
+                super::SearchKeyword::ManaValueQuery(mvq) => match mvq.operand {
+                    ManaValueOperand::Even | ManaValueOperand::Odd => {
+                        let operand = mvq.operand.describe();
+                        let is = if mvq.operator == NumericOperator::NotEqual {
+                            "is not"
+                        } else {
+                            "is"
+                        };
+                        text(format!("mana value {is} {operand}")).into()
+                    }
+                    ManaValueOperand::Number(_) => {
+                        let operator = mvq.operator.describe();
+                        let operand = mvq.operand.describe();
+                        text(format!("mana value is {operator} {operand}")).into()
+                    }
+                },
+                super::SearchKeyword::LegalityQuery(lq) => {
+                    let legality = lq.legality.describe();
+                    let format = lq.format.describe();
+                    text(format!("{legality} in {format}")).into()
+                }
+                super::SearchKeyword::SetQuery(sq) => {
+                    let set_code = &sq.set_code;
+                    text(format!(r#"set is: "{set_code}""#)).into()
+                }
+                super::SearchKeyword::SetTypeQuery(stq) => {
+                    let set_type = &stq.set_type;
+                    text(format!(r#"set type is: "{set_type}""#)).into()
+                }
+                super::SearchKeyword::CollectorNumberQuery(cnq) => {
+                    let operator = cnq.operator.describe();
+                    let operand = &cnq.operand;
+                    text(format!("collector number is {operator} {operand}")).into()
+                }
+                super::SearchKeyword::RarityQuery(rq) => {
+                    let operator = rq.operator.describe();
+                    let operand = &rq.operand;
+                    text(format!("rarity is {operator} {operand}")).into()
+                }
+                super::SearchKeyword::ManaCostQuery(mcq) => {
+                    let operand = &mcq.operand;
+                    match mcq.operator {
+                        NumericOperator::Colon => {
+                            text(format!("mana cost includes {operand}")).into()
+                        }
+                        _ => {
+                            let operator = mcq.operator.describe();
+                            text(format!("mana cost is {operator} {operand}")).into()
+                        }
+                    }
+                }
+                super::SearchKeyword::ProducesQuery(pq) => {
+                    let operator = pq.operator.describe();
+                    let color = pq.operand.describe();
+                    text(format!("produces {operator} {color}")).into()
+                }
+                super::SearchKeyword::DevotionQuery(dq) => {
+                    let operator = dq.operator.describe();
+                    let colors = dq.colors();
+                    let count = dq.count;
+                    text(format!("devotion to {colors} is {operator} {count}")).into()
+                }
+                super::SearchKeyword::ArtistQuery(aq) => {
+                    let artist = &aq.artist;
+                    text(format!(r#"artist includes: "{artist}""#)).into()
+                }
+                super::SearchKeyword::ArtistsQuery(aq) => {
+                    let operator = aq.operator.describe();
+                    let count = &aq.count;
+                    text(format!("number of artists is {operator} {count}")).into()
+                }
+                super::SearchKeyword::FlavorQuery(fq) => {
+                    let operand = &fq.flavor_text;
+                    match operand {
+                        TextOperand::Text(_) => {
+                            text(format!("flavor text contains {operand}")).into()
+                        }
+                        TextOperand::Regex(_) => {
+                            text(format!("flavor text matches {operand}")).into()
+                        }
+                    }
+                }
+                super::SearchKeyword::WatermarkQuery(wq) => match wq {
+                    WatermarkQuery::Named(watermark) => {
+                        text(format!(r#"watermark is: "{watermark}""#)).into()
+                    }
+                    WatermarkQuery::Any => text("has a watermark").into(),
+                },
+                super::SearchKeyword::YearQuery(yq) => {
+                    let operator = yq.operator.describe();
+                    let year = &yq.year;
+                    text(format!("year is {operator} {year}")).into()
+                }
+                super::SearchKeyword::DateQuery(dq) => {
+                    let operator = dq.operator.describe();
+                    match &dq.operand {
+                        DateOperand::Date(date) => {
+                            text(format!("release date is {operator} {date}")).into()
+                        }
+                        DateOperand::Set(set_code) => text(format!(
+                            r#"release date is {operator} the release of "{set_code}""#
+                        ))
+                        .into(),
+                    }
+                }
+                super::SearchKeyword::PriceQuery(pq) => {
+                    let currency = pq.currency.describe();
                     let operator = pq.operator.describe();
-                    let operand = pq.operand.describe();
-                    text(format!("power is {operator} {operand}")).into()
+                    let price = &pq.price;
+                    text(format!("{currency} price is {operator} {price}")).into()
+                }
+                super::SearchKeyword::CheapestQuery(cq) => {
+                    let currency = cq.currency.describe();
+                    text(format!("cheapest print in {currency}")).into()
+                }
+                super::SearchKeyword::LanguageQuery(lq) => match lq {
+                    LanguageQuery::Language(language) => {
+                        text(format!(r#"language is: "{language}""#)).into()
+                    }
+                    LanguageQuery::Any => text("in any language").into(),
+                },
+                super::SearchKeyword::IncludeQuery(IncludeQuery::Extras) => {
+                    text("including extras").into()
+                }
+                super::SearchKeyword::OrderQuery(oq) => {
+                    let order = oq.order.describe();
+                    text(format!("sorted by {order}")).into()
+                }
+                super::SearchKeyword::UniqueQuery(uq) => {
+                    let unique = uq.unique.describe();
+                    text(format!("showing {unique}")).into()
+                }
+                super::SearchKeyword::DirectionQuery(dq) => match dq.direction {
+                    Direction::Asc => text("sorted ascending").into(),
+                    Direction::Desc => text("sorted descending").into(),
+                },
+                super::SearchKeyword::InQuery(iq) => match iq {
+                    InQuery::Language(language) => {
+                        text(format!(r#"has been printed in: "{language}""#)).into()
+                    }
+                    InQuery::Game(game) => {
+                        let game = game.describe();
+                        text(format!("has been available in {game}")).into()
+                    }
+                },
+                super::SearchKeyword::GameQuery(gq) => {
+                    let game = gq.game.describe();
+                    text(format!("available in {game}")).into()
+                }
+                super::SearchKeyword::IdQuery(iq) => {
+                    let game_id = iq.game_id.describe();
+                    let id = &iq.id;
+                    text(format!("{game_id} is {id}")).into()
+                }
+                super::SearchKeyword::ColorCountQuery(ccq) => {
+                    let count = ccq.count.describe();
+                    let operator = ccq.operator.describe();
+                    let operand = &ccq.operand;
+                    text(format!("{count} is {operator} {operand}")).into()
+                }
+                super::SearchKeyword::IndicatorQuery(_) => text("has a color indicator").into(),
+                super::SearchKeyword::PrintsQuery(pq) => {
+                    let count = pq.count.describe();
+                    let operator = pq.operator.describe();
+                    let operand = &pq.operand;
+                    text(format!("{count} is {operator} {operand}")).into()
+                }
+                super::SearchKeyword::BorderQuery(bq) => {
+                    let border = &bq.border;
+                    text(format!(r#"border is: "{border}""#)).into()
+                }
+                super::SearchKeyword::FrameQuery(fq) => {
+                    let frame = &fq.frame;
+                    text(format!(r#"frame is: "{frame}""#)).into()
+                }
+                super::SearchKeyword::StampQuery(sq) => {
+                    let stamp = &sq.stamp;
+                    text(format!(r#"security stamp is: "{stamp}""#)).into()
+                }
+                super::SearchKeyword::ShortcutQuery(sq) => {
+                    let name = &sq.name;
+                    if sq.negated {
+                        text(format!("is not: {name}")).into()
+                    } else {
+                        text(format!("is: {name}")).into()
+                    }
+                }
+                super::SearchKeyword::IsQuery(iq) => {
+                    let predicate = &iq.predicate;
+                    if iq.negated {
+                        text(format!("is not: {predicate}")).into()
+                    } else {
+                        text(format!("is: {predicate}")).into()
+                    }
                 }
                 super::SearchKeyword::OracleQuery(oq) => {
                     let operand = &oq.oracle_text;
-                    text(format!("oracle text contains {operand}")).into()
-                }
-                super::SearchKeyword::Name(n) => {
-                    let name = &n.text;
-                    text(format!(r#"name contains: "{name}""#)).into()
+                    let full = if oq.full_text { "full " } else { "" };
+                    match operand {
+                        TextOperand::Text(_) => {
+                            text(format!("{full}oracle text contains {operand}")).into()
+                        }
+                        TextOperand::Regex(_) => {
+                            text(format!("{full}oracle text matches {operand}")).into()
+                        }
+                    }
                 }
+                super::SearchKeyword::Name(n) => match n {
+                    Name::Contains(name @ TextOperand::Text(_)) => {
+                        text(format!(r#"name contains: "{name}""#)).into()
+                    }
+                    Name::Contains(name @ TextOperand::Regex(_)) => {
+                        text(format!("name matches: {name}")).into()
+                    }
+                    Name::Exact(name) => text(format!(r#"name is exactly: "{name}""#)).into(),
+                },
                 super::SearchKeyword::TypeLineQuery(tlq) => {
                     let operand = &tlq.operand;
-                    text(format!(r#"type includes: "{operand}""#)).into()
+                    match operand {
+                        TextOperand::Text(_) => {
+                            text(format!(r#"type includes: "{operand}""#)).into()
+                        }
+                        TextOperand::Regex(_) => text(format!("type matches: {operand}")).into(),
+                    }
                 }
                 super::SearchKeyword::Keyword(kw) => {
                     let thing = &kw.keyword;
@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -32,6 +35,25 @@ pub(crate) struct ImageUris {
     pub(crate) border_crop: Option<Url>,
 }
 
+// Scryfall gives prices as strings, or null if there's no price for that
+// finish. They're stored as numbers so they can be compared and sorted.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub(crate) struct Prices {
+    pub(crate) usd: Option<String>,
+    pub(crate) usd_foil: Option<String>,
+    pub(crate) usd_etched: Option<String>,
+    pub(crate) eur: Option<String>,
+    pub(crate) eur_foil: Option<String>,
+    pub(crate) tix: Option<String>,
+}
+
+impl Prices {
+    #[allow(dead_code)]
+    pub fn parse(price: &Option<String>) -> Option<f64> {
+        price.as_ref().and_then(|price| price.parse().ok())
+    }
+}
+
 // Colors is WUBRG, etc.
 type Colors = Vec<String>;
 
@@ -134,8 +156,9 @@ pub(crate) struct Card {
     // An array of keywords that this card uses, such as 'Flying' and 'Cumulative upkeep'.
     pub(crate) keywords: Vec<String>,
 
-    // legalities	Object		An object describing the legality of this card across play formats. Possible legalities are legal, not_legal, restricted, and banned.
-    // pub(crate) legalities: Object
+    // An object describing the legality of this card across play formats.
+    // Possible legalities are legal, not_legal, restricted, and banned.
+    pub(crate) legalities: HashMap<String, String>,
 
     // This card’s life modifier, if it is Vanguard card. This value will
     // contain a delta, such as +2.
@@ -226,10 +249,8 @@ pub(crate) struct Card {
     // The flavor text, if any.
     pub(crate) flavor_text: Option<String>,
 
-    // // frame_effects	Array
-    // // Nullable
-    // // This card’s frame effects, if any.
-    // frame_effects: FrameEffects,
+    // This card’s frame effects, if any.
+    pub(crate) frame_effects: Option<Vec<String>>,
 
     // This card’s frame layout.
     pub(crate) frame: String,
@@ -258,8 +279,11 @@ pub(crate) struct Card {
     // True if this card is oversized.
     pub(crate) oversized: bool,
 
-    // // prices	Object		An object containing daily price information for this card, including usd, usd_foil, usd_etched, eur, eur_foil, eur_etched, and tix prices, as strings.
-    // prices: Prices
+    // An object containing daily price information for this card, including
+    // usd, usd_foil, usd_etched, eur, eur_foil, eur_etched, and tix prices, as
+    // strings.
+    #[serde(default)]
+    pub(crate) prices: Prices,
 
     // The localized name printed on this card, if any.
     pub(crate) printed_name: Option<String>,
@@ -289,9 +313,8 @@ pub(crate) struct Card {
     // // related_uris	Object		An object providing URIs to this card’s listing on other Magic: The Gathering online resources.
     // related_uris: Option<RelatedUris>,
 
-    // TODO - I should probably parse this into a date object instead of string.
     // The date this card was first released.
-    pub(crate) released_at: String,
+    pub(crate) released_at: NaiveDate,
 
     // True if this card is a reprint.
     pub(crate) reprint: bool,
@@ -8,15 +8,19 @@ use std::{
 };
 
 use anyhow::{Context, Result};
-use db::{ADD_CARD_FACE, ADD_CARD_FACE_IMAGE_URIS, CREATE_TABLE_SQL};
+use db::{ADD_CARD_FACE, ADD_CARD_FACE_IMAGE_URIS, BUILD_ORACLE_PRINTS, CREATE_TABLE_SQL};
 use itertools::Itertools;
 use reqwest::Client;
 use rusqlite::{named_params, Transaction};
 use serde::de::DeserializeOwned;
 use serde_json::{self, Deserializer};
-use types::{Card, CardFace};
+use types::{Card, CardFace, Prices};
 
-use crate::db::{ADD_CARD, ADD_CARD_COLOR_IDENTITY, ADD_CARD_IMAGE_URIS, ADD_CARD_KEYWORDS};
+use crate::db::{
+    ADD_CARD, ADD_CARD_COLOR_IDENTITY, ADD_CARD_FINISHES, ADD_CARD_FRAME_EFFECTS, ADD_CARD_GAMES,
+    ADD_CARD_IMAGE_URIS, ADD_CARD_KEYWORDS, ADD_CARD_LEGALITIES, ADD_CARD_PRICES,
+    ADD_CARD_PRODUCED_MANA,
+};
 
 static CARD_CHUNK_SIZE: usize = 1000;
 
@@ -25,7 +29,13 @@ fn add_card(tx: &mut Transaction, card: &Card) -> Result<()> {
     add_card_faces(tx, &card)?;
     add_keywords(tx, &card)?;
     add_color_identity(tx, &card)?;
+    add_produced_mana(tx, card)?;
+    add_games(tx, card)?;
+    add_finishes(tx, card)?;
+    add_frame_effects(tx, card)?;
+    add_legalities(tx, card)?;
     add_image_uris(tx, &card)?;
+    add_prices(tx, card)?;
     Ok(())
 }
 
@@ -51,6 +61,8 @@ fn add_base_card(tx: &mut Transaction, card: &Card) -> Result<()> {
         ":cmc": card.cmc,
         ":power": card.power,
         ":toughness": card.toughness,
+        ":loyalty": card.loyalty,
+        ":defense": card.defense,
         ":flavor_text": card.flavor_text,
         ":oracle_text": card.oracle_text,
         ":C": card.is_colorless(),
@@ -61,6 +73,27 @@ fn add_base_card(tx: &mut Transaction, card: &Card) -> Result<()> {
         ":G": card.is_green(),
         ":type_line": card.type_line,
         ":mana_cost": card.mana_cost,
+        ":set_code": card.set,
+        ":set_name": card.set_name,
+        ":set_type": card.set_type,
+        ":collector_number": card.collector_number,
+        ":rarity": card.rarity,
+        ":artist": card.artist,
+        ":watermark": card.watermark,
+        ":released_at": card.released_at,
+        ":printed_name": card.printed_name,
+        ":printed_text": card.printed_text,
+        ":reprint": card.reprint,
+        ":digital": card.digital,
+        ":border_color": card.border_color,
+        ":frame": card.frame,
+        ":full_art": card.full_art,
+        ":highres_image": card.highres_image,
+        ":security_stamp": card.security_stamp,
+        ":edhrec_rank": card.edhrec_rank,
+        ":promo": card.promo,
+        ":illustration_id": card.illustration_id,
+        ":color_indicator": card.color_indicator.as_ref().map(|colors| colors.concat()),
         },
     )?;
     Ok(())
@@ -96,6 +129,7 @@ fn add_base_card_face(
             ":toughness": &card_face.toughness,
             ":type_line": &card_face.type_line,
             ":watermark": &card_face.watermark,
+            ":color_indicator": card_face.color_indicator.as_ref().map(|colors| colors.concat()),
             ":C": card_face.is_colorless(),
             ":W": card_face.is_white(),
             ":U": card_face.is_blue(),
@@ -167,6 +201,72 @@ fn add_color_identity(tx: &mut Transaction, card: &Card) -> Result<()> {
     Ok(())
 }
 
+fn add_produced_mana(tx: &mut Transaction, card: &Card) -> Result<()> {
+    for produced_mana in card.produced_mana.iter().flatten() {
+        tx.execute(
+            ADD_CARD_PRODUCED_MANA,
+            named_params! {
+                ":card_id": &card.id,
+                ":produced_mana": produced_mana,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn add_games(tx: &mut Transaction, card: &Card) -> Result<()> {
+    for game in &card.games {
+        tx.execute(
+            ADD_CARD_GAMES,
+            named_params! {
+                ":card_id": &card.id,
+                ":game": game,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn add_finishes(tx: &mut Transaction, card: &Card) -> Result<()> {
+    for finish in &card.finishes {
+        tx.execute(
+            ADD_CARD_FINISHES,
+            named_params! {
+                ":card_id": &card.id,
+                ":finish": finish,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn add_frame_effects(tx: &mut Transaction, card: &Card) -> Result<()> {
+    for frame_effect in card.frame_effects.iter().flatten() {
+        tx.execute(
+            ADD_CARD_FRAME_EFFECTS,
+            named_params! {
+                ":card_id": &card.id,
+                ":frame_effect": frame_effect,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+fn add_legalities(tx: &mut Transaction, card: &Card) -> Result<()> {
+    for (format, legality) in &card.legalities {
+        tx.execute(
+            ADD_CARD_LEGALITIES,
+            named_params! {
+                ":card_id": &card.id,
+                ":format": format,
+                ":legality": legality,
+            },
+        )?;
+    }
+    Ok(())
+}
+
 fn add_image_uris(tx: &mut Transaction, card: &Card) -> Result<()> {
     if let Some(image_uris) = &card.image_uris {
         tx.execute(
@@ -185,6 +285,23 @@ fn add_image_uris(tx: &mut Transaction, card: &Card) -> Result<()> {
     Ok(())
 }
 
+fn add_prices(tx: &mut Transaction, card: &Card) -> Result<()> {
+    let prices = &card.prices;
+    tx.execute(
+        ADD_CARD_PRICES,
+        named_params! {
+            ":card_id": &card.id,
+            ":usd": Prices::parse(&prices.usd),
+            ":usd_foil": Prices::parse(&prices.usd_foil),
+            ":usd_etched": Prices::parse(&prices.usd_etched),
+            ":eur": Prices::parse(&prices.eur),
+            ":eur_foil": Prices::parse(&prices.eur_foil),
+            ":tix": Prices::parse(&prices.tix),
+        },
+    )?;
+    Ok(())
+}
+
 async fn run() -> Result<()> {
     let target_dir = PathBuf::from("target");
     let json_file_path = target_dir.join("cards.json");
@@ -226,6 +343,9 @@ async fn run() -> Result<()> {
         println!("Inserted {} total cards", total_cards);
     }
 
+    conn.execute_batch(BUILD_ORACLE_PRINTS)?;
+    println!("Built print counts");
+
     Ok(())
 }
 